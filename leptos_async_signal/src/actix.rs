@@ -0,0 +1,76 @@
+//! Helpers for request-scoped async signals with `leptos_actix`, mirroring
+//! the `axum` ones where that feature is enabled.
+//!
+//! A [`RequestSignal`] is created per request in an actix middleware, where
+//! its value can be seeded from the request (path, auth, headers), and added to
+//! the request by [`RequestSignal::run_service`]. Within the
+//! `additional_context` of `leptos_routes_with_context`,
+//! [`provide_request_signal`] then provides its read and write sides as
+//! context, and once the response is rendered, the middleware can read the
+//! final value.
+//!
+//! ```ignore
+//! async fn crumbs(
+//!     request: ServiceRequest,
+//!     next: Next<impl MessageBody>,
+//! ) -> Result<ServiceResponse<impl MessageBody>, Error> {
+//!     let crumbs = RequestSignal::new(Crumbs::default());
+//!     let response = crumbs.run_service(request, next).await?;
+//!     log!("crumbs: {:?}", crumbs.get());
+//!     Ok(response)
+//! }
+//!
+//! App::new()
+//!     .leptos_routes_with_context(
+//!         routes,
+//!         || _ = provide_request_signal::<Crumbs>(),
+//!         app,
+//!     )
+//!     .wrap(middleware::from_fn(crumbs))
+//! ```
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpMessage};
+use leptos::prelude::*;
+
+pub use crate::request::RequestSignal;
+use crate::AsyncReadSignal;
+
+impl<T> RequestSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Adds this signal to the request extensions, and calls the rest of the
+    /// service stack (including rendering) with it.
+    pub async fn run_service<B>(
+        &self,
+        request: ServiceRequest,
+        next: Next<B>,
+    ) -> Result<ServiceResponse<B>, Error>
+    where
+        B: MessageBody,
+    {
+        request.extensions_mut().insert(self.clone());
+        let response = next.call(request).await;
+        self.release();
+        response
+    }
+}
+
+/// Provides the read and write sides of the request-scoped signal of type `T`
+/// as context, i.e. [`AsyncReadSignal<T>`] and
+/// [`AsyncWriteSignal<T>`](crate::AsyncWriteSignal). This is meant to be
+/// called within the `additional_context` of `leptos_routes_with_context`.
+///
+/// Returns the read side, or `None` if the current request has no such signal
+/// (e.g. when generating the route list), in which case nothing is provided.
+pub fn provide_request_signal<T>() -> Option<AsyncReadSignal<T>>
+where
+    T: Send + Sync + 'static,
+{
+    let request = use_context::<leptos_actix::Request>()?;
+    let signal = request.extensions().get::<RequestSignal<T>>().cloned();
+    signal?.provide()
+}
@@ -1,27 +1,251 @@
+use std::cell::RefCell;
 use std::sync::{Arc, RwLock};
 
+use futures::future::select_all;
 use tokio::sync::Notify;
 
-#[derive(Default, Clone)]
-pub(crate) struct AsyncState {
-    inner: Arc<AsyncStateInner>,
+#[derive(Clone)]
+pub(crate) enum AsyncState {
+    /// Readiness of a single async signal, marked by its writers.
+    Signal(Arc<AsyncStateInner>),
+    /// Readiness of several async signals, which is reached once all of them
+    /// are ready.
+    All(Arc<[AsyncState]>),
+    /// Readiness of a growing set of async signals, which is reached once all
+    /// of the signals added so far are ready.
+    Dynamic(Arc<DynamicState>),
+}
+
+pub(crate) struct DynamicState {
+    /// Always ready, and only notified when a state is added.
+    added: Arc<AsyncStateInner>,
+    states: RwLock<Vec<AsyncState>>,
 }
 
 #[derive(Default)]
-struct AsyncStateInner {
-    ready: RwLock<bool>,
+pub(crate) struct AsyncStateInner {
+    status: RwLock<AsyncStatus>,
     notify: Notify,
 }
 
+type StatusUpdate = Box<dyn FnOnce(&mut AsyncStatus)>;
+type Batch = Vec<(Arc<AsyncStateInner>, StatusUpdate)>;
+
+thread_local! {
+    /// The updates deferred by the current batch, if any.
+    static BATCH: RefCell<Option<Batch>> =
+        const { RefCell::new(None) };
+}
+
+#[derive(Default)]
+struct AsyncStatus {
+    /// Set once the value was written, or all writers were released.
+    ready: bool,
+    /// Number of writes so far.
+    version: u64,
+    /// Set once all writers were released.
+    released: bool,
+}
+
+impl Default for AsyncState {
+    fn default() -> Self {
+        Self::Signal(Arc::default())
+    }
+}
+
 impl AsyncState {
+    /// Creates a state that is ready once all of the given states are ready.
+    pub fn all<'a>(states: impl IntoIterator<Item = &'a AsyncState>) -> Self {
+        Self::All(states.into_iter().cloned().collect())
+    }
+
+    /// Creates a state that is ready once all of the states added to it with
+    /// [`add`](AsyncState::add) are ready, and hence is ready until the first
+    /// one is added.
+    pub fn dynamic() -> Self {
+        let status = AsyncStatus { ready: true, version: 0, released: true };
+        let added =
+            Arc::new(AsyncStateInner { status: RwLock::new(status), notify: Notify::new() });
+        Self::Dynamic(Arc::new(DynamicState { added, states: Default::default() }))
+    }
+
+    /// Adds a state to a state created with [`dynamic`](AsyncState::dynamic).
+    pub fn add(&self, state: &AsyncState) {
+        let AsyncState::Dynamic(dynamic) = self else {
+            unreachable!("only dynamic states are added to");
+        };
+        dynamic.states.write().unwrap().push(state.clone());
+        dynamic.added.notify.notify_waiters();
+    }
+
     pub async fn wait(&self) {
-        if !*self.inner.ready.read().unwrap() {
-            self.inner.notify.notified().await;
-        }
+        self.wait_until(|status| status.ready).await;
+    }
+
+    /// Returns the version of the latest write.
+    pub fn version(&self) -> u64 {
+        self.status().version
+    }
+
+    /// Waits for a write newer than the given version (as returned by a
+    /// previous call), and returns the version of the latest write. Returns
+    /// `None` if no newer write can happen, as all writers were released.
+    pub async fn wait_newer(&self, version: u64) -> Option<u64> {
+        let mut latest = None;
+        self.wait_until(|status| {
+            if status.version > version {
+                latest = Some(status.version);
+                true
+            } else {
+                status.released
+            }
+        })
+        .await;
+        latest
     }
 
+    /// Marks the value as written.
     pub fn mark_ready(&self) {
-        *self.inner.ready.write().unwrap() = true;
-        self.inner.notify.notify_waiters();
+        self.update(|status| {
+            status.ready = true;
+            status.version += 1;
+        });
+    }
+
+    /// Marks the value as written, without making it ready (i.e. when the
+    /// written value is not final yet).
+    pub fn mark_changed(&self) {
+        self.update(|status| status.version += 1);
+    }
+
+    /// Marks the value as ready, without a new write (i.e. when the written
+    /// value equals the current one).
+    pub fn mark_ready_unchanged(&self) {
+        self.update(|status| status.ready = true);
+    }
+
+    /// Marks that all writers were released.
+    pub fn release(&self) {
+        self.update(|status| {
+            status.ready = true;
+            status.released = true;
+        });
+    }
+
+    /// Runs `fun`, deferring all status updates until it returns, so that
+    /// they are observed together. Nested batches are part of the outermost
+    /// one.
+    pub fn batch<R>(fun: impl FnOnce() -> R) -> R {
+        /// Applies the deferred updates once the batch ends, even on panic.
+        struct BatchGuard;
+
+        impl Drop for BatchGuard {
+            fn drop(&mut self) {
+                let updates = BATCH
+                    .with(|batch| batch.borrow_mut().take())
+                    .unwrap_or_default();
+                let mut notify = Vec::<Arc<AsyncStateInner>>::new();
+                for (inner, update) in updates {
+                    update(&mut inner.status.write().unwrap());
+                    if !notify.iter().any(|other| Arc::ptr_eq(other, &inner)) {
+                        notify.push(inner);
+                    }
+                }
+                for inner in notify {
+                    inner.notify.notify_waiters();
+                }
+            }
+        }
+
+        let outermost = BATCH.with(|batch| {
+            let mut batch = batch.borrow_mut();
+            if batch.is_some() {
+                return false;
+            }
+            *batch = Some(Vec::new());
+            true
+        });
+        // Only the outermost batch has a guard, as dropping it applies the updates.
+        let _guard = outermost.then(|| BatchGuard);
+        fun()
+    }
+
+    /// Applies an update to the status of a single async signal, or defers it
+    /// if within a batch.
+    fn update(&self, update: impl FnOnce(&mut AsyncStatus) + 'static) {
+        let inner = self.signal_inner();
+        let update = BATCH.with(|batch| match batch.borrow_mut().as_mut() {
+            Some(batch) => {
+                batch.push((inner.clone(), Box::new(update)));
+                None
+            }
+            None => Some(update),
+        });
+        if let Some(update) = update {
+            update(&mut inner.status.write().unwrap());
+            inner.notify.notify_waiters();
+        }
+    }
+
+    /// Waits until the condition holds for the combined status of all inner
+    /// states.
+    async fn wait_until(&self, mut cond: impl FnMut(&AsyncStatus) -> bool) {
+        loop {
+            // Created before checking the status, so that a concurrent
+            // notification is not missed.
+            let inners = self.inners();
+            let notified = inners
+                .iter()
+                .map(|inner| Box::pin(inner.notify.notified()))
+                .collect::<Vec<_>>();
+            if cond(&Self::status_of(&inners)) {
+                return;
+            }
+            select_all(notified).await;
+        }
+    }
+
+    fn status(&self) -> AsyncStatus {
+        Self::status_of(&self.inners())
+    }
+
+    fn status_of(inners: &[Arc<AsyncStateInner>]) -> AsyncStatus {
+        inners
+            .iter()
+            .fold(AsyncStatus { ready: true, version: 0, released: true }, |acc, inner| {
+                let status = inner.status.read().unwrap();
+                AsyncStatus {
+                    ready: acc.ready && status.ready,
+                    version: acc.version + status.version,
+                    released: acc.released && status.released,
+                }
+            })
+    }
+
+    /// Returns the states of the single async signals this consists of.
+    fn inners(&self) -> Vec<Arc<AsyncStateInner>> {
+        match self {
+            AsyncState::Signal(inner) => vec![inner.clone()],
+            AsyncState::All(states) => states.iter().flat_map(AsyncState::inners).collect(),
+            AsyncState::Dynamic(dynamic) => std::iter::once(dynamic.added.clone())
+                .chain(
+                    dynamic
+                        .states
+                        .read()
+                        .unwrap()
+                        .iter()
+                        .flat_map(AsyncState::inners),
+                )
+                .collect(),
+        }
+    }
+
+    fn signal_inner(&self) -> &Arc<AsyncStateInner> {
+        match self {
+            AsyncState::Signal(inner) => inner,
+            AsyncState::All(_) | AsyncState::Dynamic(_) => {
+                unreachable!("joined async states are never written to")
+            }
+        }
     }
 }
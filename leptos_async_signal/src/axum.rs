@@ -0,0 +1,69 @@
+//! Helpers for request-scoped async signals with `leptos_axum`.
+//!
+//! A [`RequestSignal`] is created per request in an axum middleware, where its
+//! value can be seeded from extractors (path, auth, headers), and added to the
+//! request by [`RequestSignal::run`]. Within the `additional_context` of
+//! `leptos_routes_with_context`, [`provide_request_signal`] then provides its
+//! read and write sides as context, and once the response is rendered, the
+//! middleware can read the final value.
+//!
+//! ```ignore
+//! async fn crumbs(
+//!     Path(id): Path<u64>,
+//!     request: Request,
+//!     next: Next,
+//! ) -> Response {
+//!     let crumbs = RequestSignal::new(Crumbs::Post { id });
+//!     let response = crumbs.run(request, next).await;
+//!     log!("crumbs: {:?}", crumbs.get());
+//!     response
+//! }
+//!
+//! Router::new()
+//!     .leptos_routes_with_context(
+//!         &options,
+//!         routes,
+//!         || _ = provide_request_signal::<Crumbs>(),
+//!         app,
+//!     )
+//!     .layer(middleware::from_fn(crumbs))
+//! ```
+
+use ::axum::extract::Request;
+use ::axum::middleware::Next;
+use ::axum::response::Response;
+use http::request::Parts;
+use leptos::prelude::*;
+
+pub use crate::request::RequestSignal;
+use crate::AsyncReadSignal;
+
+impl<T> RequestSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Adds this signal to the request extensions, and runs the rest of the
+    /// middleware stack (including rendering) with it.
+    pub async fn run(&self, mut request: Request, next: Next) -> Response {
+        request.extensions_mut().insert(self.clone());
+        let response = next.run(request).await;
+        self.release();
+        response
+    }
+}
+
+/// Provides the read and write sides of the request-scoped signal of type `T`
+/// as context, i.e. [`AsyncReadSignal<T>`] and
+/// [`AsyncWriteSignal<T>`](crate::AsyncWriteSignal). This
+/// is meant to be called within the `additional_context` of
+/// `leptos_routes_with_context`.
+///
+/// Returns the read side, or `None` if the current request has no such signal
+/// (e.g. when generating the route list), in which case nothing is provided.
+pub fn provide_request_signal<T>() -> Option<AsyncReadSignal<T>>
+where
+    T: Send + Sync + 'static,
+{
+    let parts = use_context::<Parts>()?;
+    parts.extensions.get::<RequestSignal<T>>()?.provide()
+}
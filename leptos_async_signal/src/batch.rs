@@ -0,0 +1,23 @@
+#[cfg(feature = "ssr")]
+use crate::AsyncState;
+
+/// Runs `fun`, which writes to several async signals, and makes the written
+/// signals ready together once it returns, instead of each upon its own write.
+/// In SSR mode, this lets the `<Suspense/>` boundaries reading related signals
+/// (e.g. breadcrumbs and a page title) resolve together. In other modes, this
+/// only runs `fun`.
+///
+/// Nested calls are part of the outermost one.
+///
+/// ```ignore
+/// batch_async(|| {
+///     crumbs_tx.set(crumbs);
+///     title_tx.set(title);
+/// });
+/// ```
+pub fn batch_async<R>(fun: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "ssr")]
+    return AsyncState::batch(fun);
+    #[cfg(not(feature = "ssr"))]
+    fun()
+}
@@ -0,0 +1,270 @@
+//! A breadcrumb trail built from nested `leptos_router` routes.
+//!
+//! Render a [`BreadcrumbTrail`] within the `<Router/>` (before the
+//! `<Routes/>`), a [`BreadcrumbTrailEnd`] after the `<Routes/>`, and call
+//! [`use_crumb`] within the view of each route that should have a crumb. The
+//! crumbs are ordered by route nesting, and link to the path matched by their
+//! route.
+//!
+//! In SSR mode, the trail waits until the crumb of the route matching the full
+//! path is registered (or else until the end of the trail is rendered, e.g.
+//! when that route has no crumb or no route matches), and all registered
+//! crumbs are resolved. Note that the trail does not wait for crumbs that are
+//! registered afterwards, such as crumbs of index routes (i.e. nested routes
+//! with an empty path), or of routes rendered within a `<Suspense/>`. In other
+//! modes, the trail is updated reactively as routes are entered and left.
+//!
+//! The crumbs are also available with [`use_breadcrumbs`], e.g. to render them
+//! as structured data with the `json_ld` feature.
+//!
+//! ```ignore
+//! view! {
+//!     <Router>
+//!         <BreadcrumbTrail />
+//!         <Routes fallback=|| "Page not found.">
+//!             <ParentRoute path=path!("") view=|| {
+//!                 use_crumb(async { "Home".to_string() });
+//!                 view! { <Outlet /> }
+//!             }>
+//!                 <Route path=path!("post/:id") view=PostPage />
+//!                 <Route path=path!("") view=HomePage />
+//!             </ParentRoute>
+//!         </Routes>
+//!         <BreadcrumbTrailEnd />
+//!     </Router>
+//! }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use leptos::prelude::*;
+use leptos_router::hooks::{use_location, use_matched};
+use serde::{Deserialize, Serialize};
+
+use crate::{async_signal_split, AsyncReadSignal, AsyncWriteSignal};
+
+/// A single crumb of a breadcrumb trail.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Crumb {
+    /// The label of the crumb.
+    pub label: String,
+    /// The path matched by the route of the crumb.
+    pub href: String,
+}
+
+/// A crumb registered by a route, whose label may not be resolved yet.
+struct Entry {
+    id: u64,
+    href: String,
+    leaf: bool,
+    label: Option<String>,
+}
+
+#[derive(Default)]
+struct Entries {
+    next_id: u64,
+    entries: Vec<Entry>,
+    /// Whether the routes are rendered, i.e. no more crumbs are registered.
+    settled: bool,
+}
+
+/// The state of a breadcrumb trail, provided as context to the routes.
+#[derive(Clone)]
+struct Trail {
+    entries: Arc<Mutex<Entries>>,
+    writer: AsyncWriteSignal<Vec<Crumb>>,
+    reader: AsyncReadSignal<Vec<Crumb>>,
+}
+
+impl Trail {
+    fn register(&self, href: String, leaf: bool) -> u64 {
+        let mut entries = self.lock();
+        let id = entries.next_id;
+        entries.next_id += 1;
+        // Routes match a prefix of the path of their nested routes, so ordering
+        // by the length of the matched path yields the nesting order.
+        let index = entries
+            .entries
+            .partition_point(|entry| entry.href.len() <= href.len());
+        entries
+            .entries
+            .insert(index, Entry { id, href, leaf, label: None });
+        id
+    }
+
+    fn resolve(&self, id: u64, label: String) {
+        if let Some(entry) = self.lock().entries.iter_mut().find(|entry| entry.id == id) {
+            entry.label = Some(label);
+        }
+        self.update();
+    }
+
+    fn remove(&self, id: u64) {
+        self.lock().entries.retain(|entry| entry.id != id);
+        self.update();
+    }
+
+    fn update(&self) {
+        let (crumbs, complete) = {
+            let entries = self.lock();
+            let crumbs = entries
+                .entries
+                .iter()
+                .filter_map(|entry| {
+                    let label = entry.label.clone()?;
+                    Some(Crumb { label, href: entry.href.clone() })
+                })
+                .collect::<Vec<_>>();
+            let complete = (entries.settled || entries.entries.iter().any(|entry| entry.leaf))
+                && crumbs.len() == entries.entries.len();
+            (crumbs, complete)
+        };
+        // Writing makes the trail ready in SSR mode, so only write once it is
+        // complete. In other modes, the trail is updated on each change.
+        if complete || cfg!(not(feature = "ssr")) {
+            self.writer.set(crumbs);
+        }
+    }
+
+    /// Marks the routes as rendered, so that the trail no longer waits for the
+    /// crumb of the route matching the full path, which is never registered if
+    /// that route has no crumb, or if no route matches.
+    #[cfg(feature = "ssr")]
+    fn settle(&self) {
+        self.lock().settled = true;
+        self.update();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Renders the breadcrumb trail of the current route, as a list of links to
+/// the matched paths of the routes that registered a crumb with [`use_crumb`].
+/// The last crumb, i.e. the current page, is not a link.
+///
+/// This needs to be rendered within the `<Router/>`, before the `<Routes/>`
+/// (which are followed by a [`BreadcrumbTrailEnd`]), and on both the server
+/// and the client.
+#[component]
+pub fn BreadcrumbTrail() -> impl IntoView {
+    let (crumbs_rx, crumbs_tx) = async_signal_split(Vec::<Crumb>::new());
+    let trail = Trail { entries: Default::default(), writer: crumbs_tx, reader: crumbs_rx.clone() };
+    provide_context(trail);
+    let crumbs = crumbs_rx.resource();
+
+    view! {
+        <nav aria-label="Breadcrumb">
+            <ol>
+                <Suspense>
+                    {move || {
+                        let crumbs = crumbs.clone();
+                        Suspend::new(async move {
+                            let crumbs = crumbs.await;
+                            let last = crumbs.len().saturating_sub(1);
+                            crumbs
+                                .into_iter()
+                                .enumerate()
+                                .map(|(i, Crumb { label, href })| {
+                                    if i == last {
+                                        view! {
+                                            <li>
+                                                <span aria-current="page">{label}</span>
+                                            </li>
+                                        }
+                                            .into_any()
+                                    } else {
+                                        view! {
+                                            <li>
+                                                <a href=href>{label}</a>
+                                            </li>
+                                        }
+                                            .into_any()
+                                    }
+                                })
+                                .collect_view()
+                        })
+                    }}
+                </Suspense>
+            </ol>
+        </nav>
+    }
+}
+
+/// Marks the end of the routes of the enclosing [`BreadcrumbTrail`]. This needs
+/// to be rendered within the `<Router/>`, right after the `<Routes/>`, so that
+/// in SSR mode the trail resolves once the routes are rendered, even if the
+/// route matching the full path has no crumb (or no route matches). It renders
+/// nothing.
+///
+/// This must not be rendered within a `<Suspense/>` following the trail, as the
+/// trail is rendered first, and would wait for it indefinitely.
+#[component]
+pub fn BreadcrumbTrailEnd() -> impl IntoView {
+    #[cfg(feature = "ssr")]
+    let trail = use_context::<Trail>();
+    // Settled when rendered, i.e. after the routes, rather than when created.
+    move || {
+        #[cfg(feature = "ssr")]
+        if let Some(trail) = &trail {
+            trail.settle();
+        }
+    }
+}
+
+/// Returns the crumbs of the enclosing [`BreadcrumbTrail`], e.g. to render
+/// them as structured data as well, or `None` if there is no enclosing trail.
+/// In SSR mode, the crumbs are ready once the trail is.
+pub fn use_breadcrumbs() -> Option<AsyncReadSignal<Vec<Crumb>>> {
+    use_context::<Trail>().map(|trail| trail.reader)
+}
+
+type LabelFuture = Pin<Box<dyn Future<Output = String> + Send>>;
+
+/// Registers a crumb for the current route in the enclosing
+/// [`BreadcrumbTrail`], labeled with the output of the given future. The crumb
+/// is removed once the route is left.
+///
+/// The label is resolved through a resource, so this should be called in the
+/// same place on both the server and the client, and the future is not polled
+/// when hydrating. This needs to be called within the view of a route, and does
+/// nothing if there is no enclosing trail.
+pub fn use_crumb(label: impl Future<Output = String> + Send + 'static) {
+    let Some(trail) = use_context::<Trail>() else {
+        return;
+    };
+    let href = normalize(&use_matched().get_untracked());
+    let leaf = href == normalize(&use_location().pathname.get_untracked());
+    let id = trail.register(href, leaf);
+    on_cleanup({
+        let trail = trail.clone();
+        move || trail.remove(id)
+    });
+
+    // The resource source never changes, so the future is awaited at most once.
+    let label = Mutex::new(Some(Box::pin(label) as LabelFuture));
+    let label = ArcResource::new(
+        || (),
+        move |_| {
+            let label = label.lock().unwrap_or_else(|err| err.into_inner()).take();
+            async move {
+                match label {
+                    Some(label) => label.await,
+                    None => String::new(),
+                }
+            }
+        },
+    );
+    leptos::task::spawn(async move {
+        let label = label.await;
+        trail.resolve(id, label);
+    });
+}
+
+/// Normalizes a path, so that matched paths can be compared with the location.
+fn normalize(path: &str) -> String {
+    format!("/{}", path.trim_matches('/'))
+}
@@ -0,0 +1,179 @@
+use std::future::Future;
+use std::pin::Pin;
+#[cfg(feature = "router")]
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use leptos::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{AsyncReadSignal, AsyncWriteSignal, AsyncWriteSignalInner, FallbackFn};
+#[cfg(feature = "ssr")]
+use crate::{AsyncState, ReadyFn};
+#[cfg(feature = "router")]
+use crate::{Navigation, OnNavigate};
+
+/// A builder for async signals with options beyond the ones of
+/// [`async_signal`](crate::async_signal) and
+/// [`async_signal_split`](crate::async_signal_split), which are equivalent to
+/// building with no options set.
+pub struct AsyncSignalBuilder<T>
+where
+    T: 'static,
+{
+    /// The initial value, which is only computed upon release for signals
+    /// built with a fallback.
+    default: Option<T>,
+    fallback: Option<Fallback<T>>,
+    #[cfg(feature = "ssr")]
+    ready_when: Option<ReadyFn<T>>,
+    #[cfg(feature = "router")]
+    on_navigate: OnNavigate,
+    #[cfg(feature = "router")]
+    reset: Option<Arc<dyn Fn() -> Option<T> + Send + Sync>>,
+}
+
+impl<T> AsyncSignalBuilder<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Starts building an async signal with the given default value.
+    pub fn new(default: T) -> Self {
+        Self::with_default(Some(default))
+    }
+
+    fn with_default(default: Option<T>) -> Self {
+        Self {
+            default,
+            fallback: None,
+            #[cfg(feature = "ssr")]
+            ready_when: None,
+            #[cfg(feature = "router")]
+            on_navigate: OnNavigate::default(),
+            #[cfg(feature = "router")]
+            reset: None,
+        }
+    }
+
+    /// Starts building an async signal whose default value is computed by
+    /// `fallback`, see
+    /// [`async_signal_with_fallback`](crate::async_signal_with_fallback).
+    pub fn with_fallback(fallback: impl FnOnce() -> T + Send + Sync + 'static) -> Self {
+        let mut builder = Self::with_default(None);
+        builder.fallback = Some(Fallback::Sync(Box::new(fallback)));
+        builder
+    }
+
+    /// Starts building an async signal whose default value is computed by the
+    /// future returned by `fallback`, see
+    /// [`async_signal_with_async_fallback`](crate::async_signal_with_async_fallback).
+    pub fn with_async_fallback<Fut>(fallback: impl FnOnce() -> Fut + Send + Sync + 'static) -> Self
+    where
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        let mut builder = Self::with_default(None);
+        builder.fallback =
+            Some(Fallback::Async(Box::new(move || Box::pin(fallback()) as FallbackFuture<T>)));
+        builder
+    }
+
+    /// Makes the signal ready (in SSR mode) only once a value satisfying
+    /// `ready_when` is written to it, or all clones of the write signal are
+    /// released. Other written values are still set, but the paired resource
+    /// keeps waiting for a later one, so placeholder values (e.g. a "loading"
+    /// state) can be written first. Once ready, the signal stays ready.
+    pub fn ready_when(self, ready_when: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        #[cfg(feature = "ssr")]
+        return Self { ready_when: Some(Box::new(ready_when)), ..self };
+        #[cfg(not(feature = "ssr"))]
+        {
+            let _ = ready_when;
+            self
+        }
+    }
+
+    /// Sets what happens to the signal when the `leptos_router` location
+    /// changes on the client, see [`OnNavigate`]. This needs the signal to be
+    /// built within a `<Router/>`.
+    ///
+    /// Keep in mind that with [`OnNavigate::Reset`] and
+    /// [`OnNavigate::Transition`], a page that never writes to the signal
+    /// leaves the paired resource pending.
+    #[cfg(feature = "router")]
+    pub fn on_navigate(mut self, on_navigate: OnNavigate) -> Self
+    where
+        T: Clone,
+    {
+        let default = self.default.clone();
+        self.on_navigate = on_navigate;
+        self.reset = match on_navigate {
+            OnNavigate::Reset => Some(Arc::new(move || default.clone())),
+            OnNavigate::Keep | OnNavigate::Transition => None,
+        };
+        self
+    }
+
+    /// Builds the async signal, see [`async_signal`](crate::async_signal).
+    pub fn build(self) -> (ArcResource<T>, AsyncWriteSignal<T>)
+    where
+        T: Clone + Serialize + DeserializeOwned,
+    {
+        let (signal_read, signal_write) = self.build_split();
+        (signal_read.resource(), signal_write)
+    }
+
+    /// Builds the async signal with its read side as an [`AsyncReadSignal`],
+    /// see [`async_signal_split`](crate::async_signal_split).
+    pub fn build_split(self) -> (AsyncReadSignal<T>, AsyncWriteSignal<T>) {
+        let signal = ArcRwSignal::new(self.default);
+        #[cfg(feature = "ssr")]
+        let state = AsyncState::default();
+        #[cfg(feature = "router")]
+        let navigation = Navigation::new(self.on_navigate, signal.clone(), self.reset);
+        let async_read_signal = AsyncReadSignal {
+            signal_read: signal.read_only().into(),
+            #[cfg(feature = "ssr")]
+            state: state.clone(),
+            #[cfg(feature = "router")]
+            navigation: navigation.clone().into(),
+        };
+        let fallback = self.fallback.map(|fallback| {
+            let signal = signal.clone();
+            #[cfg(feature = "ssr")]
+            let state = state.clone();
+            Box::new(move || match fallback {
+                Fallback::Sync(fallback) => {
+                    signal.set(Some(fallback()));
+                    #[cfg(feature = "ssr")]
+                    state.release();
+                }
+                Fallback::Async(fallback) => leptos::task::spawn(async move {
+                    signal.set(Some(fallback().await));
+                    #[cfg(feature = "ssr")]
+                    state.release();
+                }),
+            }) as FallbackFn
+        });
+        let inner = AsyncWriteSignalInner::Signal {
+            signal,
+            #[cfg(feature = "ssr")]
+            state,
+            #[cfg(feature = "router")]
+            navigation,
+            #[cfg(feature = "ssr")]
+            ready_when: self.ready_when,
+            fallback: Mutex::new(fallback),
+        };
+        (async_read_signal, AsyncWriteSignal::new(inner))
+    }
+}
+
+type FallbackFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// Computes the default value of an async signal that is released without
+/// being written to.
+enum Fallback<T> {
+    Sync(Box<dyn FnOnce() -> T + Send + Sync>),
+    Async(Box<dyn FnOnce() -> FallbackFuture<T> + Send + Sync>),
+}
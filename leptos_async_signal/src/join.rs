@@ -0,0 +1,70 @@
+use leptos::prelude::*;
+
+#[cfg(feature = "router")]
+use crate::navigation::NavigationSet;
+use crate::AsyncReadSignal;
+#[cfg(feature = "ssr")]
+use crate::AsyncState;
+
+/// A tuple of async read signals that can be joined with [`join_async`].
+///
+/// This is implemented for tuples of [`AsyncReadSignal`] with up to eight
+/// elements.
+pub trait JoinAsync {
+    /// The tuple of values of the joined signals.
+    type Value: Send + Sync + 'static;
+
+    /// Joins the signals, see [`join_async`].
+    fn join_async(self) -> AsyncReadSignal<Self::Value>;
+}
+
+/// Joins several async read signals into a single one, whose value is the
+/// tuple of their values. In SSR mode, the joined signal is ready once all of
+/// the given signals are ready. After navigating, it is pending while any of
+/// the given signals is (see `AsyncSignalBuilder::on_navigate` with the
+/// `router` feature).
+///
+/// This allows depending on several async signals with a single resource (and
+/// hence a single `<Suspense/>`), which is also serialized only once, instead
+/// of once per signal.
+pub fn join_async<J>(signals: J) -> AsyncReadSignal<J::Value>
+where
+    J: JoinAsync,
+{
+    signals.join_async()
+}
+
+macro_rules! impl_join_async {
+    ($($ty:ident $signal:ident),+) => {
+        impl<$($ty),+> JoinAsync for ($(AsyncReadSignal<$ty>,)+)
+        where
+            $($ty: Clone + Send + Sync + PartialEq + 'static),+
+        {
+            type Value = ($($ty,)+);
+
+            fn join_async(self) -> AsyncReadSignal<Self::Value> {
+                let ($($signal,)+) = self;
+                #[cfg(feature = "ssr")]
+                let state = AsyncState::all([$(&$signal.state),+]);
+                #[cfg(feature = "router")]
+                let navigation = NavigationSet::all([$(&$signal.navigation),+]);
+                let memo = ArcMemo::new(move |_| Some(($($signal.signal_read.get()?,)+)));
+                AsyncReadSignal {
+                    signal_read: memo.into(),
+                    #[cfg(feature = "ssr")]
+                    state,
+                    #[cfg(feature = "router")]
+                    navigation,
+                }
+            }
+        }
+    };
+}
+
+impl_join_async!(A a, B b);
+impl_join_async!(A a, B b, C c);
+impl_join_async!(A a, B b, C c, D d);
+impl_join_async!(A a, B b, C c, D d, E e);
+impl_join_async!(A a, B b, C c, D d, E e, F f);
+impl_join_async!(A a, B b, C c, D d, E e, F f, G g);
+impl_join_async!(A a, B b, C c, D d, E e, F f, G g, H h);
@@ -0,0 +1,154 @@
+//! Structured data rendered as JSON-LD from async signals.
+//!
+//! Render a [`JsonLd`] with the read side of an async signal, whose value is
+//! serialized into a `<script type="application/ld+json">` block once it is
+//! ready. In SSR mode, the block is thus part of the page served to crawlers.
+//!
+//! [`BreadcrumbList`] maps a breadcrumb trail to the schema.org
+//! `BreadcrumbList` type, e.g. the trail of the `BreadcrumbTrail` with the
+//! `breadcrumbs` feature:
+//!
+//! ```ignore
+//! let crumbs = use_breadcrumbs().expect("within the breadcrumb trail");
+//! let structured = crumbs.map(|crumbs| {
+//!     crumbs
+//!         .iter()
+//!         .collect::<BreadcrumbList>()
+//!         .base_url("https://example.com")
+//! });
+//! view! { <JsonLd signal=structured /> }
+//! ```
+
+use leptos::prelude::*;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+#[cfg(feature = "breadcrumbs")]
+use crate::breadcrumbs::Crumb;
+use crate::{AsyncReadSignal, AsyncView};
+
+/// Renders the value of an async signal as a `<script
+/// type="application/ld+json">` block (see [`to_json_ld`]), within a
+/// `<Suspense/>`. Nothing is rendered if the value fails to serialize.
+#[component]
+pub fn JsonLd<T>(
+    /// The async signal holding the structured data.
+    signal: AsyncReadSignal<T>,
+) -> impl IntoView
+where
+    T: Serialize + Send + Sync + 'static,
+{
+    // Only the serialized value is paired with a resource.
+    let json = signal.map(|value| to_json_ld(value).ok());
+    view! {
+        <AsyncView signal=json let:json>
+            {json.map(|json| view! { <script type="application/ld+json" inner_html=json></script> })}
+        </AsyncView>
+    }
+}
+
+/// Serializes a value to JSON that can be embedded in a `<script>` element.
+///
+/// The characters `<`, `>` and `&` are escaped as unicode escapes, which only
+/// occur within JSON strings, so that the value cannot close the element (e.g.
+/// with `</script>`) nor open a comment.
+pub fn to_json_ld<T>(value: &T) -> serde_json::Result<String>
+where
+    T: Serialize + ?Sized,
+{
+    let json = serde_json::to_string(value)?;
+    let mut escaped = String::with_capacity(json.len());
+    for c in json.chars() {
+        match c {
+            '<' => escaped.push_str("\\u003c"),
+            '>' => escaped.push_str("\\u003e"),
+            '&' => escaped.push_str("\\u0026"),
+            c => escaped.push(c),
+        }
+    }
+    Ok(escaped)
+}
+
+/// A schema.org `BreadcrumbList`, serialized with its `@context`, so that it
+/// can be rendered with [`JsonLd`] as is.
+///
+/// Items are positioned in the order they are added. Relative URLs are
+/// resolved against the base URL, if one is set, as search engines expect
+/// absolute URLs.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BreadcrumbList {
+    base_url: Option<String>,
+    items: Vec<BreadcrumbItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BreadcrumbItem {
+    name: String,
+    url: Option<String>,
+}
+
+impl BreadcrumbList {
+    /// Sets the base URL against which relative item URLs are resolved.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Adds an item with the given name, linking to the given URL. The URL may
+    /// be omitted for the last item, i.e. the current page.
+    pub fn item(mut self, name: impl Into<String>, url: Option<impl Into<String>>) -> Self {
+        self.items
+            .push(BreadcrumbItem { name: name.into(), url: url.map(Into::into) });
+        self
+    }
+
+    fn resolve(&self, url: &str) -> String {
+        match &self.base_url {
+            Some(base_url) if url.starts_with('/') && !url.starts_with("//") => {
+                format!("{}{url}", base_url.trim_end_matches('/'))
+            }
+            _ => url.to_string(),
+        }
+    }
+}
+
+/// Maps a breadcrumb trail, where the last crumb is the current page.
+#[cfg(feature = "breadcrumbs")]
+impl<'a> FromIterator<&'a Crumb> for BreadcrumbList {
+    fn from_iter<I: IntoIterator<Item = &'a Crumb>>(crumbs: I) -> Self {
+        crumbs.into_iter().fold(Self::default(), |list, crumb| {
+            list.item(crumb.label.clone(), Some(crumb.href.clone()))
+        })
+    }
+}
+
+impl Serialize for BreadcrumbList {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct ListItem<'a> {
+            #[serde(rename = "@type")]
+            type_: &'static str,
+            position: usize,
+            name: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            item: Option<String>,
+        }
+
+        let items = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| ListItem {
+                type_: "ListItem",
+                position: i + 1,
+                name: &item.name,
+                item: item.url.as_deref().map(|url| self.resolve(url)),
+            })
+            .collect::<Vec<_>>();
+        let mut list = serializer.serialize_struct("BreadcrumbList", 3)?;
+        list.serialize_field("@context", "https://schema.org")?;
+        list.serialize_field("@type", "BreadcrumbList")?;
+        list.serialize_field("itemListElement", &items)?;
+        list.end()
+    }
+}
@@ -34,16 +34,57 @@
 //! | 0.8.x    | 0.6.0             |
 //! | 0.7.x    | 0.5.0             |
 
-use std::sync::Arc;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
+#[cfg(feature = "ssr")]
+use futures::Stream;
 use leptos::prelude::*;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+#[cfg(feature = "actix")]
+pub mod actix;
 #[cfg(feature = "ssr")]
 mod async_state;
+#[cfg(feature = "axum")]
+pub mod axum;
+mod batch;
+#[cfg(feature = "breadcrumbs")]
+pub mod breadcrumbs;
+mod builder;
+mod join;
+#[cfg(feature = "json_ld")]
+pub mod json_ld;
+mod map;
+#[cfg(feature = "meta")]
+pub mod meta;
+#[cfg(feature = "router")]
+mod navigation;
+#[cfg(any(feature = "axum", feature = "actix"))]
+mod request;
+mod response;
+mod result_ext;
+mod result_signal;
+mod slot;
+mod view;
+
 #[cfg(feature = "ssr")]
 use async_state::AsyncState;
+pub use batch::batch_async;
+pub use builder::AsyncSignalBuilder;
+pub use join::{join_async, JoinAsync};
+pub use map::{async_signal_map, AsyncSignalMap};
+#[cfg(feature = "router")]
+pub use navigation::OnNavigate;
+#[cfg(feature = "router")]
+use navigation::{Navigation, NavigationSet};
+pub use response::{bind_response, AsyncResponse, ResponseMode};
+pub use result_ext::AsyncSignalResultExt;
+pub use result_signal::{async_result_signal, AsyncResultWriteSignal};
+pub use slot::{provide_async_slot, use_async_slot, AsyncSlot};
+pub use view::AsyncView;
 
 /// An async write signal. This is almost the same as the regular Leptos (Arc)
 /// write signal, but under the hood also takes care of notifying the resource
@@ -54,22 +95,101 @@ use async_state::AsyncState;
 /// allow it to return the default value it holds.  Conversely, keeping clones
 /// of this signal around and never calling write/set will ensure the resource
 /// will never return, which typically manifest in the SSR hanging forever.
-#[derive(Clone)]
+///
+/// Signals derived with [`contramap`](AsyncWriteSignal::contramap),
+/// [`lens`](AsyncWriteSignal::lens) or
+/// [`supersede`](AsyncWriteSignal::supersede) hold a clone of the signal they
+/// were derived from, and hence count as its clones in the above.
 pub struct AsyncWriteSignal<T>
 where
     T: 'static,
 {
     inner: Arc<AsyncWriteSignalInner<T>>,
+    /// The latest epoch handed out by
+    /// [`supersede`](AsyncWriteSignal::supersede).
+    epoch: Arc<AtomicU64>,
+}
+
+impl<T> Clone for AsyncWriteSignal<T> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), epoch: self.epoch.clone() }
+    }
+}
+
+impl<T> AsyncWriteSignal<T> {
+    fn new(inner: AsyncWriteSignalInner<T>) -> Self {
+        Self { inner: Arc::new(inner), epoch: Default::default() }
+    }
 }
 
-#[derive(Clone)]
-struct AsyncWriteSignalInner<T>
+/// Writes the value, and returns whether it was not discarded.
+type SetFn<T> = Box<dyn Fn(T) -> bool + Send + Sync>;
+/// Updates the value in place with a function that returns whether it changed
+/// the value, and returns whether the value was changed.
+type UpdateFn<T> = Box<dyn Fn(&mut dyn FnMut(&mut T) -> bool) -> bool + Send + Sync>;
+type FallbackFn = Box<dyn FnOnce() + Send + Sync>;
+#[cfg(feature = "ssr")]
+type ReadyFn<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+
+enum AsyncWriteSignalInner<T>
 where
     T: 'static,
 {
-    signal_write: ArcWriteSignal<T>,
+    /// Writes to the signal paired with the resource, which holds no value
+    /// until a signal built with a fallback is written to or falls back.
+    Signal {
+        signal: ArcRwSignal<Option<T>>,
+        #[cfg(feature = "ssr")]
+        state: AsyncState,
+        #[cfg(feature = "router")]
+        navigation: Option<Arc<Navigation>>,
+        /// Whether a written value makes the signal ready, see
+        /// [`AsyncSignalBuilder::ready_when`].
+        #[cfg(feature = "ssr")]
+        ready_when: Option<ReadyFn<T>>,
+        /// Computes the fallback value if the signal is released without being
+        /// written to, see [`async_signal_with_fallback`].
+        fallback: Mutex<Option<FallbackFn>>,
+    },
+    /// Forwards writes to the async write signal this one was derived from.
+    /// Only lenses are able to update the value in place, and only superseding
+    /// signals keep the signal they were derived from, to read from it.
+    Mapped { set: SetFn<T>, update: Option<UpdateFn<T>>, source: Option<AsyncWriteSignal<T>> },
+}
+
+/// The read side of an async signal that is not (yet) paired with a
+/// resource. It holds the current value of the signal, and in SSR mode also
+/// knows when the value is ready, i.e. once the paired [`AsyncWriteSignal`]
+/// was written to or dropped.
+///
+/// Use [`resource`](AsyncReadSignal::resource) to obtain the resource that
+/// waits for the value in SSR mode, and carries it to the client.
+pub struct AsyncReadSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// The value, which is `None` until a signal built with a fallback is
+    /// written to or falls back.
+    signal_read: ArcSignal<Option<T>>,
     #[cfg(feature = "ssr")]
     state: AsyncState,
+    #[cfg(feature = "router")]
+    navigation: NavigationSet,
+}
+
+impl<T> Clone for AsyncReadSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            signal_read: self.signal_read.clone(),
+            #[cfg(feature = "ssr")]
+            state: self.state.clone(),
+            #[cfg(feature = "router")]
+            navigation: self.navigation.clone(),
+        }
+    }
 }
 
 /// Creates a new async signal, that is, the pairing of a resource with an
@@ -78,34 +198,537 @@ where
 /// this works is documented by [`AsyncWriteSignal`].
 pub fn async_signal<T>(default: T) -> (ArcResource<T>, AsyncWriteSignal<T>)
 where
-    T: Clone + Send + Sync + PartialEq + Serialize + DeserializeOwned,
+    T: Clone + Send + Sync + Serialize + DeserializeOwned,
 {
-    let (signal_read, signal_write) = arc_signal(default);
+    let (signal_read, signal_write) = async_signal_split(default);
+    (signal_read.resource(), signal_write)
+}
+
+/// Creates a new async signal like [`async_signal`], but returns its read side
+/// as an [`AsyncReadSignal`] instead of a resource. This allows deriving other
+/// values from it before pairing them with a resource.
+pub fn async_signal_split<T>(default: T) -> (AsyncReadSignal<T>, AsyncWriteSignal<T>)
+where
+    T: Send + Sync + 'static,
+{
+    AsyncSignalBuilder::new(default).build_split()
+}
+
+/// Creates a new async signal like [`async_signal`], but with a default value
+/// that is computed by `fallback` only once all clones of the async write
+/// signal are released without any of them being written to.
+///
+/// Until then, the signal holds no value, so the paired resource is pending
+/// (unless it is hydrated from the server). Hence, `T` does not need a
+/// placeholder value such as `T::default()`.
+pub fn async_signal_with_fallback<T>(
+    fallback: impl FnOnce() -> T + Send + Sync + 'static,
+) -> (ArcResource<T>, AsyncWriteSignal<T>)
+where
+    T: Clone + Send + Sync + Serialize + DeserializeOwned,
+{
+    AsyncSignalBuilder::with_fallback(fallback).build()
+}
+
+/// Creates a new async signal like [`async_signal_with_fallback`], but with a
+/// default value that is computed asynchronously, e.g. by a database lookup.
+/// In SSR mode, the paired resource waits for the future returned by
+/// `fallback` when all clones of the async write signal are released without
+/// being written to.
+pub fn async_signal_with_async_fallback<T, Fut>(
+    fallback: impl FnOnce() -> Fut + Send + Sync + 'static,
+) -> (ArcResource<T>, AsyncWriteSignal<T>)
+where
+    T: Clone + Send + Sync + Serialize + DeserializeOwned,
+    Fut: Future<Output = T> + Send + 'static,
+{
+    AsyncSignalBuilder::with_async_fallback(fallback).build()
+}
+
+/// Creates a new async signal like [`async_signal`], but pairs it with an async
+/// derived value that is never serialized to the client instead of a resource,
+/// see [`AsyncReadSignal::server_only`]. This does not require the value to be
+/// serializable, but the value is only meant for server-side consumers.
+pub fn async_signal_server_only<T>(default: T) -> (ArcAsyncDerived<T>, AsyncWriteSignal<T>)
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let (signal_read, signal_write) = async_signal_split(default);
+    (signal_read.server_only(), signal_write)
+}
+
+impl<T> AsyncReadSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Derives a read signal whose value is mapped with `f` from the value of
+    /// this signal. The mapped value is memoized, and is ready (in SSR mode)
+    /// exactly when the value of this signal is.
+    ///
+    /// Unlike deriving a new resource from the resource of this signal, this
+    /// waits for the same write, and only the mapped value is serialized once
+    /// it is paired with a resource.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U + Send + Sync + 'static) -> AsyncReadSignal<U>
+    where
+        U: Send + Sync + PartialEq + 'static,
+    {
+        let signal_read = self.signal_read.clone();
+        let memo = ArcMemo::new(move |_| signal_read.with(|value| value.as_ref().map(&f)));
+        AsyncReadSignal {
+            signal_read: memo.into(),
+            #[cfg(feature = "ssr")]
+            state: self.state.clone(),
+            #[cfg(feature = "router")]
+            navigation: self.navigation.clone(),
+        }
+    }
+
+    /// Returns a stream of the values of this signal, meant for server-side
+    /// consumers that need the value outside of the rendering (e.g. for
+    /// logging or computing response headers).
+    ///
+    /// The stream first yields the value once it is ready, and then the value
+    /// upon each subsequent write. Writes that happen while the consumer is
+    /// busy are coalesced, i.e. only the latest value is yielded. The stream
+    /// ends once all writers are released.
     #[cfg(feature = "ssr")]
-    let state = AsyncState::default();
-    let inner = AsyncWriteSignalInner {
-        signal_write,
-        #[cfg(feature = "ssr")]
-        state: state.clone(),
-    };
-    let resource = ArcResource::new(
-        {
+    pub fn to_stream(&self) -> impl Stream<Item = T> + Send + 'static
+    where
+        T: Clone,
+    {
+        let signal_read = self.signal_read.clone();
+        let state = self.state.clone();
+        futures::stream::unfold(None, move |version| {
             let signal_read = signal_read.clone();
-            move || signal_read.get()
-        },
-        move |_| {
-            #[cfg(feature = "ssr")]
             let state = state.clone();
-            let signal_read = signal_read.clone();
             async move {
+                let version = match version {
+                    None => {
+                        state.wait().await;
+                        state.version()
+                    }
+                    Some(version) => state.wait_newer(version).await?,
+                };
+                let value = signal_read
+                    .get_untracked()
+                    .expect("ready signal holds a value");
+                Some((value, Some(version)))
+            }
+        })
+    }
+
+    /// Creates a resource paired with this signal. In SSR mode, the resource
+    /// waits for the value to be ready, and it is serialized to the client as
+    /// any other resource. In other modes, the resource is updated whenever the
+    /// value changes.
+    ///
+    /// Each call creates a new resource, so this should be called in the same
+    /// place on both the server and the client.
+    ///
+    /// The value is cloned into the resource once per change. For large values,
+    /// read the resource with `with` rather than `get` to avoid further clones.
+    pub fn resource(&self) -> ArcResource<T>
+    where
+        T: Clone + Serialize + DeserializeOwned,
+    {
+        self.resource_with(false)
+    }
+
+    /// Creates a blocking resource paired with this signal, i.e. just like
+    /// [`resource`](AsyncReadSignal::resource), except that in SSR mode with
+    /// streaming, the response is not sent until the value is ready. This is
+    /// needed for values that end up in `<head>`, such as the title.
+    pub fn blocking_resource(&self) -> ArcResource<T>
+    where
+        T: Clone + Serialize + DeserializeOwned,
+    {
+        self.resource_with(true)
+    }
+
+    fn resource_with(&self, blocking: bool) -> ArcResource<T>
+    where
+        T: Clone + Serialize + DeserializeOwned,
+    {
+        let this = self.clone();
+        // The source is a version that is bumped whenever the value changes,
+        // so that the value is only cloned by the fetcher.
+        let version = AtomicU64::new(0);
+        let source = {
+            let this = this.clone();
+            move || {
+                this.signal_read.track();
+                (version.fetch_add(1, Ordering::Relaxed), this.track_pending())
+            }
+        };
+        let fetcher = move |_| this.ready_value();
+        if blocking {
+            ArcResource::new_blocking(source, fetcher)
+        } else {
+            ArcResource::new(source, fetcher)
+        }
+    }
+
+    /// Creates an async derived value paired with this signal, which is never
+    /// serialized to the client. In SSR mode, it waits for the value to be
+    /// ready just like the resource does (see
+    /// [`resource`](AsyncReadSignal::resource)).
+    ///
+    /// On the client, the value is not hydrated, but is the value of the signal
+    /// there, i.e. whatever the client writes to it, or else the default. Hence
+    /// this is meant for server-side consumers only (e.g. inputs of response
+    /// headers, or logging), and must not be rendered into hydrated views, as
+    /// the client would not render the same content as the server did.
+    pub fn server_only(&self) -> ArcAsyncDerived<T>
+    where
+        T: Clone,
+    {
+        let this = self.clone();
+        ArcAsyncDerived::new(move || {
+            this.signal_read.track();
+            this.track_pending();
+            this.ready_value()
+        })
+    }
+
+    /// Whether the signal is pending after navigation, tracking changes of
+    /// that. Becoming pending needs to re-run the resources paired with the
+    /// signal.
+    fn track_pending(&self) -> bool {
+        #[cfg(feature = "router")]
+        return self.navigation.track_pending();
+        #[cfg(not(feature = "router"))]
+        false
+    }
+
+    /// Waits for the value to be ready (in SSR mode) and to no longer be
+    /// pending after navigation, and returns it.
+    fn ready_value(&self) -> impl Future<Output = T> + Send + 'static
+    where
+        T: Clone,
+    {
+        let this = self.clone();
+        async move {
+            #[cfg(feature = "ssr")]
+            this.state.wait().await;
+            #[cfg(feature = "router")]
+            this.navigation.wait().await;
+            match this.signal_read.get_untracked() {
+                Some(value) => value,
+                // Before a signal built with a fallback is written to or falls
+                // back (never the case once ready in SSR mode). Its readers
+                // track the value, so they are re-run once it is set.
+                None => std::future::pending().await,
+            }
+        }
+    }
+
+    /// Creates a reactive read path for this signal, whose value is `None`
+    /// while it is pending. In SSR mode, this reads the resource paired with
+    /// the signal (see [`resource`](AsyncReadSignal::resource)), so it waits
+    /// within a `<Suspense/>` just like the resource does.
+    ///
+    /// In other modes, the paired resource only carries the value hydrated from
+    /// the server. Once the signal is written to on the client, the value is
+    /// read from the signal directly, without re-running the resource. Just
+    /// like for the resource, this should be called in the same place on both
+    /// the server and the client.
+    pub fn signal(&self) -> ArcSignal<Option<T>>
+    where
+        T: Clone + Serialize + DeserializeOwned,
+    {
+        #[cfg(feature = "ssr")]
+        {
+            let resource = self.resource();
+            ArcSignal::derive(move || resource.get())
+        }
+        #[cfg(not(feature = "ssr"))]
+        {
+            let signal_read = self.signal_read.clone();
+            // Unlike `resource`, the source does not track the value, so the
+            // resource is only re-run when becoming pending after navigation.
+            let resource = ArcResource::new(
+                {
+                    let this = self.clone();
+                    move || this.track_pending()
+                },
+                {
+                    let this = self.clone();
+                    move |_| this.ready_value()
+                },
+            );
+            // Whether the signal was written to since this was created, which
+            // means that its value supersedes the hydrated one. The memo is
+            // evaluated right away, so that it only changes upon later writes.
+            let written = ArcMemo::new({
+                let signal_read = signal_read.clone();
+                move |prev: Option<&bool>| {
+                    signal_read.track();
+                    prev.is_some()
+                }
+            });
+            written.get_untracked();
+            ArcSignal::derive(move || {
+                if written.get() && resource.with(Option::is_some) {
+                    signal_read.get()
+                } else {
+                    resource.get()
+                }
+            })
+        }
+    }
+}
+
+impl<T> AsyncWriteSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Derives an async write signal for a different value type, which is
+    /// mapped with `f` into the value of this signal upon each write.
+    ///
+    /// This is useful when a component produces a narrower value than the one
+    /// this signal holds. The derived signal shares the readiness of this
+    /// signal: writing to it notifies the paired resource, while dropping it
+    /// releases its hold on this signal.
+    pub fn contramap<U>(&self, f: impl Fn(U) -> T + Send + Sync + 'static) -> AsyncWriteSignal<U>
+    where
+        U: 'static,
+    {
+        let this = self.clone();
+        let inner = AsyncWriteSignalInner::Mapped {
+            set: Box::new(move |value| {
+                this.set(f(value));
+                true
+            }),
+            update: None,
+            source: None,
+        };
+        AsyncWriteSignal::new(inner)
+    }
+
+    /// Derives an async write signal for a part of the value of this signal,
+    /// as given by `focus`. Writes to the derived signal update the value of
+    /// this signal in place, keeping the rest of it intact. For a signal built
+    /// with a fallback, such writes are ignored until it holds a value, i.e.
+    /// once it is written to directly.
+    ///
+    /// Readiness is shared in the same way as for
+    /// [`contramap`](AsyncWriteSignal::contramap).
+    ///
+    /// # Panics
+    ///
+    /// Panics if this signal was derived with
+    /// [`contramap`](AsyncWriteSignal::contramap), as such a signal does not
+    /// hold a value that could be updated in place.
+    pub fn lens<U>(
+        &self,
+        focus: impl Fn(&mut T) -> &mut U + Send + Sync + 'static,
+    ) -> AsyncWriteSignal<U>
+    where
+        U: 'static,
+    {
+        assert!(
+            !matches!(*self.inner, AsyncWriteSignalInner::Mapped { update: None, .. }),
+            "cannot derive a lens from an async write signal created by contramap"
+        );
+        let focus = Arc::new(focus);
+        let set = {
+            let this = self.clone();
+            let focus = focus.clone();
+            move |value| {
+                let mut value = Some(value);
+                this.update_in_place(&mut |outer| {
+                    if let Some(value) = value.take() {
+                        *focus(outer) = value;
+                    }
+                    true
+                })
+            }
+        };
+        let update = {
+            let this = self.clone();
+            move |fun: &mut dyn FnMut(&mut U) -> bool| {
+                this.update_in_place(&mut |outer| fun(focus(outer)))
+            }
+        };
+        let inner = AsyncWriteSignalInner::Mapped {
+            set: Box::new(set),
+            update: Some(Box::new(update)),
+            source: None,
+        };
+        AsyncWriteSignal::new(inner)
+    }
+
+    /// Derives an async write signal that supersedes all signals previously
+    /// derived with this method from this signal (or any of its clones), i.e.
+    /// writes to those are ignored from now on. Writes to the derived signal
+    /// are also ignored once the reactive owner that was current when calling
+    /// this is cleaned up.
+    ///
+    /// This is meant for writes from async tasks that can be outrun by newer
+    /// ones, e.g. calling this in the fetcher of a resource ensures that a slow
+    /// response for a previous page does not overwrite the value written for
+    /// the current one. Ignored writes do not make the value ready.
+    pub fn supersede(&self) -> AsyncWriteSignal<T> {
+        let epoch = self.epoch.fetch_add(1, Ordering::SeqCst) + 1;
+        let alive = Arc::new(AtomicBool::new(true));
+        on_cleanup({
+            let alive = alive.clone();
+            move || alive.store(false, Ordering::SeqCst)
+        });
+        let is_current = {
+            let latest = self.epoch.clone();
+            move || alive.load(Ordering::SeqCst) && latest.load(Ordering::SeqCst) == epoch
+        };
+        let set = {
+            let this = self.clone();
+            let is_current = is_current.clone();
+            move |value| {
+                let current = is_current();
+                if current {
+                    this.set(value);
+                }
+                current
+            }
+        };
+        // Updating in place is only possible if this signal supports it.
+        let update = match &*self.inner {
+            AsyncWriteSignalInner::Mapped { update: None, .. } => None,
+            _ => {
+                let this = self.clone();
+                Some(Box::new(move |fun: &mut dyn FnMut(&mut T) -> bool| {
+                    is_current() && this.update_in_place(fun)
+                }) as UpdateFn<T>)
+            }
+        };
+        let inner = AsyncWriteSignalInner::Mapped {
+            set: Box::new(set),
+            update,
+            source: Some(self.clone()),
+        };
+        AsyncWriteSignal { inner: Arc::new(inner), epoch: self.epoch.clone() }
+    }
+
+    /// Returns a new read side of this signal, bound to the same value and
+    /// readiness as the read side returned when the signal was created. For a
+    /// signal derived with [`supersede`](AsyncWriteSignal::supersede), this is
+    /// the read side of the signal it was derived from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this signal was derived with
+    /// [`contramap`](AsyncWriteSignal::contramap) or
+    /// [`lens`](AsyncWriteSignal::lens) (or superseded from such a signal), as
+    /// such a signal does not hold a value of its own type.
+    pub fn reader(&self) -> AsyncReadSignal<T> {
+        match &*self.inner {
+            AsyncWriteSignalInner::Signal {
+                signal,
+                #[cfg(feature = "ssr")]
+                state,
+                #[cfg(feature = "router")]
+                navigation,
+                ..
+            } => AsyncReadSignal {
+                signal_read: signal.read_only().into(),
                 #[cfg(feature = "ssr")]
-                state.wait().await;
-                signal_read.get_untracked()
+                state: state.clone(),
+                #[cfg(feature = "router")]
+                navigation: navigation.clone().into(),
+            },
+            AsyncWriteSignalInner::Mapped { source: Some(source), .. } => source.reader(),
+            AsyncWriteSignalInner::Mapped { source: None, .. } => {
+                panic!("cannot read from an async write signal derived from another one")
             }
-        },
-    );
-    let async_write_signal = AsyncWriteSignal { inner: Arc::new(inner) };
-    (resource, async_write_signal)
+        }
+    }
+
+    /// Creates an additional resource paired with this signal. This is a
+    /// shorthand for `self.reader().resource()`, see
+    /// [`AsyncReadSignal::resource`].
+    ///
+    /// Each resource is hydrated independently, so the resources can be read
+    /// under different `<Suspense/>` boundaries.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`reader`](AsyncWriteSignal::reader).
+    pub fn subscribe(&self) -> ArcResource<T>
+    where
+        T: Clone + Serialize + DeserializeOwned,
+    {
+        self.reader().resource()
+    }
+
+    /// Writes the value only if it differs from the current one, and returns
+    /// whether it did. Skipping an equal value means that the paired resources
+    /// are not re-run, nothing is re-rendered, and nothing is yielded by the
+    /// stream of the value (`AsyncReadSignal::to_stream` in SSR mode). Either
+    /// way, this makes the value ready in SSR mode.
+    ///
+    /// Signals derived with [`lens`](AsyncWriteSignal::lens) compare the part
+    /// of the value they focus on, and signals derived with
+    /// [`supersede`](AsyncWriteSignal::supersede) compare against the value of
+    /// the signal they were derived from. Writes that are discarded (i.e. by a
+    /// superseded signal, or through a lens before a signal built with a
+    /// fallback holds a value) return `false`. Signals derived with
+    /// [`contramap`](AsyncWriteSignal::contramap) cannot compare against the
+    /// value of the signal they write to, so they always write the value and
+    /// return `true` (unless discarded).
+    pub fn set_if_changed(&self, value: T) -> bool
+    where
+        T: PartialEq,
+    {
+        match &*self.inner {
+            AsyncWriteSignalInner::Signal { signal, .. } => {
+                let changed = signal.with_untracked(|current| current.as_ref() != Some(&value));
+                if changed {
+                    signal.set(Some(value));
+                }
+                self.inner.mark_written(changed);
+                changed
+            }
+            AsyncWriteSignalInner::Mapped { update: Some(update), .. } => {
+                let mut value = Some(value);
+                update(&mut |current| {
+                    let changed = value.as_ref().is_some_and(|value| current != value);
+                    if changed {
+                        *current = value.take().expect("value is only taken once");
+                    }
+                    changed
+                })
+            }
+            AsyncWriteSignalInner::Mapped { update: None, set, .. } => set(value),
+        }
+    }
+
+    /// Updates the value in place with `fun`, which returns whether it changed
+    /// the value, and returns whether the value was changed.
+    fn update_in_place(&self, fun: &mut dyn FnMut(&mut T) -> bool) -> bool {
+        match &*self.inner {
+            AsyncWriteSignalInner::Signal { signal, .. } => {
+                // A value that is not set yet (i.e. before a signal built with a
+                // fallback is written to) cannot be updated in place, so it
+                // still falls back.
+                let mut written = false;
+                let mut changed = false;
+                signal.maybe_update(|value| {
+                    if let Some(value) = value {
+                        written = true;
+                        changed = fun(value);
+                    }
+                    changed
+                });
+                if written {
+                    self.inner.mark_written(changed);
+                }
+                changed
+            }
+            AsyncWriteSignalInner::Mapped { update: Some(update), .. } => update(fun),
+            AsyncWriteSignalInner::Mapped { update: None, .. } => {
+                unreachable!("lenses are never derived from contramapped signals")
+            }
+        }
+    }
 }
 
 impl<T> Set for AsyncWriteSignal<T>
@@ -115,24 +738,98 @@ where
     type Value = T;
 
     fn set(&self, value: Self::Value) {
-        self.inner.signal_write.set(value);
-        #[cfg(feature = "ssr")]
-        self.inner.state.mark_ready();
+        match &*self.inner {
+            AsyncWriteSignalInner::Signal { signal, .. } => {
+                signal.set(Some(value));
+                self.inner.mark_written(true);
+            }
+            AsyncWriteSignalInner::Mapped { set, .. } => {
+                set(value);
+            }
+        }
     }
 
     fn try_set(&self, value: Self::Value) -> Option<Self::Value> {
-        let res = self.inner.signal_write.try_set(value);
-        #[cfg(feature = "ssr")]
-        self.inner.state.mark_ready();
-        res
+        match &*self.inner {
+            AsyncWriteSignalInner::Signal { signal, .. } => {
+                let res = signal.try_set(Some(value)).flatten();
+                self.inner.mark_written(true);
+                res
+            }
+            AsyncWriteSignalInner::Mapped { set, .. } => {
+                set(value);
+                None
+            }
+        }
     }
 }
 
-#[cfg(feature = "ssr")]
-impl<T> Drop for AsyncWriteSignal<T> {
+impl<T> AsyncWriteSignalInner<T> {
+    /// Records a write to the signal, which makes its value ready (in SSR
+    /// mode) unless it does not satisfy `ready_when`. An unchanged value is not
+    /// yielded anew by streams.
+    fn mark_written(&self, changed: bool) {
+        if let AsyncWriteSignalInner::Signal {
+            #[cfg(feature = "ssr")]
+            signal,
+            #[cfg(feature = "ssr")]
+            state,
+            #[cfg(feature = "router")]
+            navigation,
+            #[cfg(feature = "ssr")]
+            ready_when,
+            fallback,
+            ..
+        } = self
+        {
+            // The fallback is no longer needed once written to.
+            fallback
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .take();
+            #[cfg(feature = "ssr")]
+            {
+                let ready = ready_when.as_ref().map_or(true, |ready_when| {
+                    signal.with_untracked(|value| value.as_ref().is_some_and(ready_when))
+                });
+                match (changed, ready) {
+                    (true, true) => state.mark_ready(),
+                    (false, true) => state.mark_ready_unchanged(),
+                    (true, false) => state.mark_changed(),
+                    (false, false) => {}
+                }
+            }
+            #[cfg(feature = "router")]
+            if let Some(navigation) = navigation {
+                navigation.written();
+            }
+        }
+        #[cfg(not(feature = "ssr"))]
+        let _ = changed;
+    }
+}
+
+impl<T> Drop for AsyncWriteSignalInner<T> {
     fn drop(&mut self) {
-        if let Some(inner) = Arc::get_mut(&mut self.inner) {
-            inner.state.mark_ready()
+        if let AsyncWriteSignalInner::Signal {
+            #[cfg(feature = "ssr")]
+            state,
+            fallback,
+            ..
+        } = self
+        {
+            // The fallback releases the state once its value is set.
+            match fallback
+                .get_mut()
+                .unwrap_or_else(|err| err.into_inner())
+                .take()
+            {
+                Some(fallback) => fallback(),
+                #[cfg(feature = "ssr")]
+                None => state.release(),
+                #[cfg(not(feature = "ssr"))]
+                None => {}
+            }
         }
     }
 }
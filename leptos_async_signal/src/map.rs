@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+
+use leptos::prelude::*;
+
+#[cfg(feature = "ssr")]
+use crate::AsyncState;
+use crate::{AsyncReadSignal, AsyncWriteSignal, AsyncWriteSignalInner};
+
+/// A map of async signals, one per key, created with [`async_signal_map`].
+///
+/// Each key has its own async signal, whose write side is obtained with
+/// [`writer`](AsyncSignalMap::writer) and read side with
+/// [`reader`](AsyncSignalMap::reader), and which is ready on its own, just like
+/// an async signal created with [`async_signal`](crate::async_signal). The read
+/// side of the whole map is obtained with [`all`](AsyncSignalMap::all).
+///
+/// This is cheap to clone, and typically provided as context, so that
+/// sections of a page each publish a value under their own key.
+pub struct AsyncSignalMap<K, V>
+where
+    K: 'static,
+    V: 'static,
+{
+    inner: Arc<MapInner<K, V>>,
+}
+
+impl<K, V> Clone for AsyncSignalMap<K, V> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+struct MapInner<K, V>
+where
+    V: 'static,
+{
+    default: V,
+    entries: Mutex<BTreeMap<K, Entry<V>>>,
+    /// Notified when a key is requested.
+    requested: ArcTrigger,
+    /// Readiness of all requested keys.
+    #[cfg(feature = "ssr")]
+    state: AsyncState,
+}
+
+/// The async signal of a single key.
+struct Entry<V>
+where
+    V: 'static,
+{
+    signal: ArcRwSignal<Option<V>>,
+    #[cfg(feature = "ssr")]
+    state: AsyncState,
+    epoch: Arc<AtomicU64>,
+    /// The write side, which is released once all of its clones are dropped.
+    writer: Weak<AsyncWriteSignalInner<V>>,
+    /// Whether the whole map waits for this key.
+    requested: bool,
+}
+
+/// Creates a new map of async signals, where the signal of each key initially
+/// holds (a clone of) the given default value. See [`AsyncSignalMap`].
+pub fn async_signal_map<K, V>(default: V) -> AsyncSignalMap<K, V>
+where
+    K: Ord + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    AsyncSignalMap {
+        inner: Arc::new(MapInner {
+            default,
+            entries: Default::default(),
+            requested: ArcTrigger::new(),
+            #[cfg(feature = "ssr")]
+            state: AsyncState::dynamic(),
+        }),
+    }
+}
+
+impl<K, V> AsyncSignalMap<K, V>
+where
+    K: Ord + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    /// Returns the write side of the async signal of the given key, and
+    /// requests the key, i.e. the whole map waits for it to be ready.
+    ///
+    /// All writers of a key are clones of each other, so the key is ready once
+    /// any of them is written to, or all of them are released.
+    pub fn writer(&self, key: &K) -> AsyncWriteSignal<V> {
+        let mut entries = self.lock();
+        let requested = self.request(&mut entries, key);
+        let entry = entries.get_mut(key).expect("requested key has an entry");
+        let inner = entry.writer.upgrade().unwrap_or_else(|| {
+            let inner = Arc::new(AsyncWriteSignalInner::Signal {
+                signal: entry.signal.clone(),
+                #[cfg(feature = "ssr")]
+                state: entry.state.clone(),
+                #[cfg(feature = "router")]
+                navigation: None,
+                #[cfg(feature = "ssr")]
+                ready_when: None,
+                fallback: Mutex::new(None),
+            });
+            entry.writer = Arc::downgrade(&inner);
+            inner
+        });
+        let writer = AsyncWriteSignal { inner, epoch: entry.epoch.clone() };
+        drop(entries);
+        if requested {
+            self.inner.requested.notify();
+        }
+        writer
+    }
+
+    /// Requests the given keys without writing to them yet, so that the whole
+    /// map waits for them even if their writers are obtained later. A requested
+    /// key whose writer is never obtained keeps the whole map pending.
+    pub fn expect(&self, keys: impl IntoIterator<Item = K>) {
+        let mut entries = self.lock();
+        let mut requested = false;
+        for key in keys {
+            requested |= self.request(&mut entries, &key);
+        }
+        drop(entries);
+        if requested {
+            self.inner.requested.notify();
+        }
+    }
+
+    /// Returns the read side of the async signal of the given key. This does
+    /// not request the key, and in SSR mode the key is not ready until its
+    /// writer is obtained and written to (or released).
+    pub fn reader(&self, key: &K) -> AsyncReadSignal<V> {
+        let mut entries = self.lock();
+        let entry = self.entry(&mut entries, key);
+        AsyncReadSignal {
+            signal_read: entry.signal.read_only().into(),
+            #[cfg(feature = "ssr")]
+            state: entry.state.clone(),
+            #[cfg(feature = "router")]
+            navigation: Default::default(),
+        }
+    }
+
+    /// Returns the read side of the whole map, holding the values of all
+    /// requested keys. In SSR mode, it is ready once all keys requested so far
+    /// are ready.
+    ///
+    /// Keys requested after the whole map was ready are not waited for. Hence,
+    /// the writers of all keys should be obtained while rendering (before any
+    /// `.await`), or the keys should be requested upfront with
+    /// [`expect`](AsyncSignalMap::expect).
+    pub fn all(&self) -> AsyncReadSignal<BTreeMap<K, V>> {
+        let this = self.clone();
+        AsyncReadSignal {
+            signal_read: ArcSignal::derive(move || {
+                this.inner.requested.track();
+                this.lock()
+                    .iter()
+                    .filter(|(_, entry)| entry.requested)
+                    .map(|(key, entry)| Some((key.clone(), entry.signal.get()?)))
+                    .collect()
+            }),
+            #[cfg(feature = "ssr")]
+            state: self.inner.state.clone(),
+            #[cfg(feature = "router")]
+            navigation: Default::default(),
+        }
+    }
+
+    /// Requests the given key, and returns whether it was not requested before.
+    fn request(&self, entries: &mut BTreeMap<K, Entry<V>>, key: &K) -> bool {
+        let entry = self.entry(entries, key);
+        if entry.requested {
+            return false;
+        }
+        entry.requested = true;
+        #[cfg(feature = "ssr")]
+        self.inner.state.add(&entry.state);
+        true
+    }
+
+    fn entry<'a>(&self, entries: &'a mut BTreeMap<K, Entry<V>>, key: &K) -> &'a mut Entry<V> {
+        entries.entry(key.clone()).or_insert_with(|| Entry {
+            signal: ArcRwSignal::new(Some(self.inner.default.clone())),
+            #[cfg(feature = "ssr")]
+            state: AsyncState::default(),
+            epoch: Default::default(),
+            writer: Weak::new(),
+            requested: false,
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<K, Entry<V>>> {
+        self.inner
+            .entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+}
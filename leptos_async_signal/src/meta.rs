@@ -0,0 +1,149 @@
+//! Components that set `leptos_meta` tags from async signals.
+//!
+//! Each component takes the read side of an async signal holding the text of
+//! the tag, and renders the corresponding `leptos_meta` component once the
+//! value is ready. The value is read through a blocking resource (see
+//! [`AsyncReadSignal::blocking_resource`]), so that in SSR mode with streaming
+//! the response is held until the value is ready, and the tag lands in
+//! `<head>` (i.e. in `<MetaTags/>`). In other modes, the tag is updated
+//! whenever the value changes.
+//!
+//! ```ignore
+//! let (title_rx, title_tx) = async_signal_split(String::from("My blog"));
+//! provide_context(title_tx);
+//! view! {
+//!     <AsyncTitle signal=title_rx formatter=|title| format!("{title} | My blog") />
+//!     <Router>...</Router>
+//! }
+//! ```
+
+use leptos::prelude::*;
+use leptos::text_prop::TextProp;
+use leptos_meta::{Formatter, Link, LinkProps, Meta, MetaProps, Title, TitleProps};
+
+use crate::AsyncReadSignal;
+
+/// Sets the document title to the value of an async signal. See the
+/// [module documentation](self).
+#[component]
+pub fn AsyncTitle(
+    /// The async signal holding the title.
+    signal: AsyncReadSignal<String>,
+    /// A function that formats the title, e.g. to add a site name.
+    #[prop(optional, into)]
+    formatter: Option<Formatter>,
+) -> impl IntoView {
+    let title = signal.blocking_resource();
+    view! {
+        <Suspense>
+            {Suspend::new(async move {
+                // `<Title/>` reads its text right away, i.e. outside of the
+                // suspense, so it reads a signal kept in sync with the resource
+                // (which is only needed in other modes, as the value is final
+                // once ready in SSR mode).
+                let text = ArcRwSignal::new(title.clone().await);
+                #[cfg(not(feature = "ssr"))]
+                Effect::new({
+                    let text = text.clone();
+                    move |_| {
+                        if let Some(value) = title.get() {
+                            text.set(value);
+                        }
+                    }
+                });
+                Title(TitleProps { formatter, text: Some((move || text.get()).into()) })
+            })}
+        </Suspense>
+    }
+}
+
+/// Sets a `<meta>` tag, whose `content` is the value of an async signal. See
+/// the [module documentation](self).
+#[component]
+pub fn AsyncMeta(
+    /// The async signal holding the `content` attribute.
+    signal: AsyncReadSignal<String>,
+    /// The `name` attribute.
+    #[prop(optional, into)]
+    name: Option<TextProp>,
+    /// The `property` attribute.
+    #[prop(optional, into)]
+    property: Option<TextProp>,
+    /// The `http-equiv` attribute.
+    #[prop(optional, into)]
+    http_equiv: Option<TextProp>,
+    /// The `itemprop` attribute.
+    #[prop(optional, into)]
+    itemprop: Option<TextProp>,
+) -> impl IntoView {
+    let content = signal.blocking_resource();
+    view! {
+        <Suspense>
+            {Suspend::new(async move {
+                content.clone().await;
+                let content = move || content.get().unwrap_or_default();
+                Meta(MetaProps {
+                    charset: None,
+                    name,
+                    property,
+                    http_equiv,
+                    itemprop,
+                    content: Some(content.into()),
+                })
+            })}
+        </Suspense>
+    }
+}
+
+/// Sets a `<link>` tag, whose `href` is the value of an async signal. See the
+/// [module documentation](self).
+#[component]
+pub fn AsyncLink(
+    /// The async signal holding the `href` attribute.
+    signal: AsyncReadSignal<String>,
+    /// The `rel` attribute.
+    #[prop(optional, into)]
+    rel: Option<Oco<'static, str>>,
+    /// The `hreflang` attribute.
+    #[prop(optional, into)]
+    hreflang: Option<Oco<'static, str>>,
+    /// The `media` attribute.
+    #[prop(optional, into)]
+    media: Option<Oco<'static, str>>,
+    /// The `type` attribute.
+    #[prop(optional, into)]
+    type_: Option<Oco<'static, str>>,
+    /// The `title` attribute.
+    #[prop(optional, into)]
+    title: Option<Oco<'static, str>>,
+) -> impl IntoView {
+    let href = signal.blocking_resource();
+    // Unlike the other tags, the attributes of `<Link/>` are not reactive, so
+    // it is rendered again whenever the value changes.
+    view! {
+        <Suspense>
+            {move || {
+                href.get().map(|href| {
+                    Link(LinkProps {
+                        id: None,
+                        as_: None,
+                        crossorigin: None,
+                        fetchpriority: None,
+                        href: Some(href.into()),
+                        hreflang: hreflang.clone(),
+                        imagesizes: None,
+                        imagesrcset: None,
+                        integrity: None,
+                        media: media.clone(),
+                        referrerpolicy: None,
+                        rel: rel.clone(),
+                        sizes: None,
+                        title: title.clone(),
+                        type_: type_.clone(),
+                        blocking: None,
+                    })
+                })
+            }}
+        </Suspense>
+    }
+}
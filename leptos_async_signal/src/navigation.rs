@@ -0,0 +1,176 @@
+use std::sync::{Arc, Mutex};
+
+use futures::channel::oneshot;
+use leptos::prelude::*;
+use leptos_router::location::Location;
+
+/// What happens to an async signal when the `leptos_router` location (i.e. its
+/// path) changes on the client. See
+/// [`AsyncSignalBuilder::on_navigate`](crate::AsyncSignalBuilder::on_navigate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnNavigate {
+    /// The value is kept until it is written to by the new page.
+    #[default]
+    Keep,
+    /// The value is reset to the default one, and the paired resource is
+    /// pending until the new page writes to the signal. Within a
+    /// `<Suspense/>`, this shows the fallback in the meantime.
+    Reset,
+    /// The paired resource is pending until the new page writes to the signal,
+    /// while the value is kept. Within a `<Transition/>`, this keeps showing
+    /// the previous value in the meantime.
+    Transition,
+}
+
+/// Tracks navigation for an async signal that is reset on navigation.
+pub(crate) struct Navigation {
+    location: Location,
+    state: Mutex<NavigationState>,
+    trigger: ArcTrigger,
+}
+
+#[derive(Default)]
+struct NavigationState {
+    pending: bool,
+    /// The path at which the signal was last written to, since the last
+    /// navigation.
+    written_at: Option<String>,
+    waiters: Vec<oneshot::Sender<()>>,
+}
+
+impl Navigation {
+    /// Starts tracking the location for the given signal, unless `on_navigate`
+    /// is [`OnNavigate::Keep`] or there is no router. The `reset` function
+    /// returns the default value, and is only needed for [`OnNavigate::Reset`].
+    pub(crate) fn new<T>(
+        on_navigate: OnNavigate,
+        signal: ArcRwSignal<T>,
+        reset: Option<Arc<dyn Fn() -> T + Send + Sync>>,
+    ) -> Option<Arc<Self>>
+    where
+        T: Send + Sync + 'static,
+    {
+        if on_navigate == OnNavigate::Keep {
+            return None;
+        }
+        let Some(location) = use_context::<Location>() else {
+            leptos::logging::debug_warn!(
+                "async signal created outside of a <Router/> is not reset on navigation"
+            );
+            return None;
+        };
+        let navigation =
+            Arc::new(Self { location, state: Default::default(), trigger: ArcTrigger::new() });
+        // Effects only run on the client, which is the only place where the
+        // location changes.
+        Effect::new({
+            let navigation = navigation.clone();
+            move |prev: Option<String>| {
+                let path = navigation.location.pathname.get();
+                if prev.is_some_and(|prev| prev != path) && navigation.navigated(&path) {
+                    if let Some(reset) = &reset {
+                        signal.set(reset());
+                    }
+                }
+                path
+            }
+        });
+        Some(navigation)
+    }
+
+    /// Marks the signal as pending after navigating to `path`, unless the new
+    /// page has already written to it. Returns whether it was marked.
+    fn navigated(&self, path: &str) -> bool {
+        let mut state = self.lock();
+        // The path of the last write only tells about the page navigated to,
+        // so that returning to a path written to before is pending again.
+        if state.written_at.take().as_deref() == Some(path) {
+            return false;
+        }
+        state.pending = true;
+        drop(state);
+        self.trigger.notify();
+        true
+    }
+
+    /// Records a write to the signal, which ends the pending state.
+    pub(crate) fn written(&self) {
+        let mut state = self.lock();
+        state.written_at = Some(self.location.pathname.get_untracked());
+        let was_pending = std::mem::take(&mut state.pending);
+        let waiters = std::mem::take(&mut state.waiters);
+        drop(state);
+        for waiter in waiters {
+            let _ = waiter.send(());
+        }
+        if was_pending {
+            self.trigger.notify();
+        }
+    }
+
+    /// Returns whether the signal is pending, and subscribes to changes of
+    /// that.
+    pub(crate) fn track_pending(&self) -> bool {
+        self.trigger.track();
+        self.lock().pending
+    }
+
+    /// Waits until the signal is no longer pending.
+    pub(crate) async fn wait(&self) {
+        let waiter = {
+            let mut state = self.lock();
+            if !state.pending {
+                return;
+            }
+            let (tx, rx) = oneshot::channel();
+            state.waiters.push(tx);
+            rx
+        };
+        let _ = waiter.await;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, NavigationState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// The navigation tracked by the read side of an async signal, i.e. that of
+/// the signals it reads from, which are several ones for joined signals. The
+/// read side is pending while any of them is.
+#[derive(Clone, Default)]
+pub(crate) struct NavigationSet(Arc<[Arc<Navigation>]>);
+
+impl NavigationSet {
+    /// Combines the navigation of several read sides.
+    pub(crate) fn all<'a>(sets: impl IntoIterator<Item = &'a NavigationSet>) -> Self {
+        Self(
+            sets.into_iter()
+                .flat_map(|set| set.0.iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// Returns whether any signal is pending, and subscribes to changes of
+    /// that for all of them.
+    pub(crate) fn track_pending(&self) -> bool {
+        // All of them are tracked, so this does not short-circuit.
+        let mut pending = false;
+        for navigation in self.0.iter() {
+            pending |= navigation.track_pending();
+        }
+        pending
+    }
+
+    /// Waits until no signal is pending.
+    pub(crate) async fn wait(&self) {
+        for navigation in self.0.iter() {
+            navigation.wait().await;
+        }
+    }
+}
+
+impl From<Option<Arc<Navigation>>> for NavigationSet {
+    fn from(navigation: Option<Arc<Navigation>>) -> Self {
+        Self(navigation.into_iter().collect())
+    }
+}
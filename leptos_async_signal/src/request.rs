@@ -0,0 +1,97 @@
+use std::sync::{Arc, Mutex};
+
+use leptos::prelude::*;
+
+use crate::{async_signal_split, AsyncReadSignal, AsyncWriteSignal};
+
+/// An async signal scoped to a single request. See the `axum` and `actix`
+/// modules for how it is used with a server integration.
+///
+/// The value is ready once it is written to while rendering, or once the
+/// rendering finishes without writing to it, in which case it keeps its seeded
+/// value.
+pub struct RequestSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    reader: AsyncReadSignal<T>,
+    writer: Arc<Mutex<Option<AsyncWriteSignal<T>>>>,
+}
+
+impl<T> Clone for RequestSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        Self { reader: self.reader.clone(), writer: self.writer.clone() }
+    }
+}
+
+impl<T> RequestSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Creates a new request-scoped signal, seeded with the given value.
+    pub fn new(seed: T) -> Self {
+        let (reader, writer) = async_signal_split(seed);
+        Self { reader, writer: Arc::new(Mutex::new(Some(writer))) }
+    }
+
+    /// Returns the read side of this signal.
+    pub fn reader(&self) -> AsyncReadSignal<T> {
+        self.reader.clone()
+    }
+
+    /// Returns the current value of this signal without waiting for it to be
+    /// ready. Once the response is rendered, this is the final value for
+    /// pages rendered with `SsrMode::Async`, or when the value is bound to the
+    /// response with [`ResponseMode::Blocking`](crate::ResponseMode).
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.reader
+            .signal_read
+            .get_untracked()
+            .expect("request signal is seeded")
+    }
+
+    /// Waits for the value of this signal to be ready, and returns it.
+    ///
+    /// If the rendering never writes to the signal, this waits until the
+    /// response body is fully streamed, so in a middleware this should only be
+    /// awaited in a spawned task. In any case, the signal has to be run with
+    /// the rest of the middleware stack, as it is only ready once rendered.
+    pub async fn value(&self) -> T
+    where
+        T: Clone,
+    {
+        self.reader.state.wait().await;
+        self.get()
+    }
+
+    /// Provides the read and write sides of this signal as context. The writer
+    /// can be provided only once.
+    pub(crate) fn provide(&self) -> Option<AsyncReadSignal<T>> {
+        // The writer is moved into the reactive owner, so that it is released
+        // once the rendering is done.
+        let writer = self.take_writer()?;
+        provide_context(writer);
+        provide_context(self.reader());
+        Some(self.reader())
+    }
+
+    /// Releases the writer if it was never provided, i.e. when the rendering
+    /// has finished without using this signal, so that it keeps its seeded
+    /// value.
+    pub(crate) fn release(&self) {
+        drop(self.take_writer());
+    }
+
+    fn take_writer(&self) -> Option<AsyncWriteSignal<T>> {
+        self.writer
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take()
+    }
+}
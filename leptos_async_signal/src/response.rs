@@ -0,0 +1,167 @@
+#[cfg(any(feature = "axum", feature = "actix"))]
+use std::sync::Arc;
+
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+use leptos::prelude::*;
+
+use crate::AsyncReadSignal;
+
+/// The HTTP response status and headers derived from the value of an async
+/// signal. See [`bind_response`].
+#[derive(Debug, Clone, Default)]
+pub struct AsyncResponse {
+    /// The response status, if it should be set.
+    pub status: Option<StatusCode>,
+    /// The response headers to set. Each header listed here replaces any value
+    /// that was previously set for the same header name.
+    pub headers: HeaderMap,
+}
+
+impl AsyncResponse {
+    /// Sets the response status.
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Adds a response header. Adding the same header name more than once
+    /// yields multiple values for that header.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+}
+
+/// When the response status and headers are applied, relative to the start of
+/// the response. See [`bind_response`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResponseMode {
+    /// The response is not delayed. The status and headers are applied once
+    /// the signal is ready, and hence only take effect if that happens before
+    /// the response head is sent (as is always the case with
+    /// `SsrMode::Async`).
+    #[default]
+    NonBlocking,
+    /// The response head is delayed until the signal is ready, just like with
+    /// blocking resources. This works with any SSR mode, at the cost of not
+    /// streaming anything before the signal is ready.
+    Blocking,
+}
+
+/// Binds the final value of an async signal to the HTTP response status and
+/// headers. Once the signal is ready in SSR mode, `response` is called with its
+/// value, and the result is applied to the `ResponseOptions` of the server
+/// integration provided as context (this requires either the `axum` or the
+/// `actix` feature). In other modes this does nothing.
+///
+/// The binding is backed by a resource (holding no data), so this should be
+/// called in the same place on both the server and the client.
+pub fn bind_response<T>(
+    signal: &AsyncReadSignal<T>,
+    mode: ResponseMode,
+    response: impl Fn(&T) -> AsyncResponse + Send + Sync + 'static,
+) where
+    T: Send + Sync + 'static,
+{
+    #[cfg(any(feature = "axum", feature = "actix"))]
+    let options = ResponseOptions::from_context();
+    #[cfg(any(feature = "axum", feature = "actix"))]
+    let response = Arc::new(response);
+    #[cfg(not(any(feature = "axum", feature = "actix")))]
+    let _ = response;
+    let signal = signal.clone();
+    let fetcher = move |_| {
+        let signal = signal.clone();
+        #[cfg(any(feature = "axum", feature = "actix"))]
+        let (options, response) = (options.clone(), Arc::clone(&response));
+        async move {
+            #[cfg(feature = "ssr")]
+            signal.state.wait().await;
+            #[cfg(any(feature = "axum", feature = "actix"))]
+            for options in options {
+                if let Some(response) = signal
+                    .signal_read
+                    .with_untracked(|value| value.as_ref().map(|value| response(value)))
+                {
+                    options.apply(&response);
+                }
+            }
+            #[cfg(not(any(feature = "axum", feature = "actix")))]
+            let _ = signal;
+        }
+    };
+    let resource = match mode {
+        ResponseMode::NonBlocking => ArcResource::new(|| (), fetcher),
+        ResponseMode::Blocking => ArcResource::new_blocking(|| (), fetcher),
+    };
+    // Keep the resource alive for as long as the current owner is.
+    on_cleanup(move || drop(resource));
+}
+
+/// The response options of one of the server integrations.
+#[cfg(any(feature = "axum", feature = "actix"))]
+#[derive(Clone)]
+enum ResponseOptions {
+    #[cfg(feature = "axum")]
+    Axum(leptos_axum::ResponseOptions),
+    #[cfg(feature = "actix")]
+    Actix(leptos_actix::ResponseOptions),
+}
+
+#[cfg(any(feature = "axum", feature = "actix"))]
+impl ResponseOptions {
+    /// Collects the response options provided as context.
+    fn from_context() -> Vec<Self> {
+        let mut options = Vec::new();
+        #[cfg(feature = "axum")]
+        options.extend(use_context::<leptos_axum::ResponseOptions>().map(Self::Axum));
+        #[cfg(feature = "actix")]
+        options.extend(use_context::<leptos_actix::ResponseOptions>().map(Self::Actix));
+        options
+    }
+
+    fn apply(&self, response: &AsyncResponse) {
+        match self {
+            #[cfg(feature = "axum")]
+            Self::Axum(options) => {
+                if let Some(status) = response.status {
+                    options.set_status(status);
+                }
+                for name in response.headers.keys() {
+                    for (i, value) in response.headers.get_all(name).iter().enumerate() {
+                        match i {
+                            0 => options.insert_header(name.clone(), value.clone()),
+                            _ => options.append_header(name.clone(), value.clone()),
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "actix")]
+            Self::Actix(options) => {
+                use actix_web::http::header::{HeaderName, HeaderValue};
+                use actix_web::http::StatusCode;
+
+                // Actix uses its own version of the `http` types.
+                if let Some(status) = response.status {
+                    if let Ok(status) = StatusCode::from_u16(status.as_u16()) {
+                        options.set_status(status);
+                    }
+                }
+                for name in response.headers.keys() {
+                    let Ok(name) = HeaderName::from_bytes(name.as_str().as_bytes()) else {
+                        continue;
+                    };
+                    for (i, value) in response.headers.get_all(name.as_str()).iter().enumerate() {
+                        let Ok(value) = HeaderValue::from_bytes(value.as_bytes()) else {
+                            continue;
+                        };
+                        match i {
+                            0 => options.insert_header(name.clone(), value),
+                            _ => options.append_header(name.clone(), value),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::future::Future;
+
+use leptos::prelude::*;
+
+use crate::AsyncWriteSignal;
+
+/// Bridges futures that resolve to a [`Result`] (typically calls to server
+/// functions) with async signals.
+///
+/// This is implemented for every such future, so it is enough to bring the
+/// trait into scope and call [`set_signal`](Self::set_signal) on the future
+/// returned by a server function.
+pub trait AsyncSignalResultExt<T, E>: Future<Output = Result<T, E>> + Sized {
+    /// Wraps this future so that, once it resolves, the async signal is set
+    /// from its outcome. The `Ok` value is mapped to the signal value with
+    /// `on_ok`, while the `Err` case is mapped with `on_err`, which acts as a
+    /// fallback.
+    ///
+    /// The returned future resolves to the original result, so it can be used
+    /// as the fetcher of a regular resource. The signal is moved into the
+    /// returned future, and hence the paired resource will wait for this
+    /// future to resolve (or be dropped) in SSR mode.
+    fn set_signal<U>(
+        self,
+        signal: AsyncWriteSignal<U>,
+        on_ok: impl FnOnce(&T) -> U,
+        on_err: impl FnOnce(&E) -> U,
+    ) -> impl Future<Output = Result<T, E>>
+    where
+        U: Send + Sync + 'static;
+}
+
+impl<F, T, E> AsyncSignalResultExt<T, E> for F
+where
+    F: Future<Output = Result<T, E>>,
+{
+    async fn set_signal<U>(
+        self,
+        signal: AsyncWriteSignal<U>,
+        on_ok: impl FnOnce(&T) -> U,
+        on_err: impl FnOnce(&E) -> U,
+    ) -> Result<T, E>
+    where
+        U: Send + Sync + 'static,
+    {
+        let result = self.await;
+        signal.set(match &result {
+            Ok(value) => on_ok(value),
+            Err(err) => on_err(err),
+        });
+        result
+    }
+}
@@ -0,0 +1,77 @@
+use leptos::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{async_signal, AsyncWriteSignal};
+
+/// An async write signal with an error channel. Values are written with
+/// [`set`](Set::set), while errors are written with
+/// [`fail`](AsyncResultWriteSignal::fail). Either of these makes the paired
+/// resource resolve (in SSR mode), to `Ok` or `Err` respectively.
+///
+/// Readiness works exactly like for [`AsyncWriteSignal`], which this signal
+/// wraps.
+pub struct AsyncResultWriteSignal<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    inner: AsyncWriteSignal<Result<T, E>>,
+}
+
+impl<T, E> Clone for AsyncResultWriteSignal<T, E> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+/// Creates a new async signal whose resource yields a [`Result`]. The default
+/// value is used (as `Ok`) in the case that the async write signal is never
+/// written to.
+///
+/// As the resource yields a `Result`, its value can be rendered within an
+/// `<ErrorBoundary/>` when the error type implements [`std::error::Error`].
+pub fn async_result_signal<T, E>(
+    default: T,
+) -> (ArcResource<Result<T, E>>, AsyncResultWriteSignal<T, E>)
+where
+    T: Clone + Send + Sync + Serialize + DeserializeOwned,
+    E: Clone + Send + Sync + Serialize + DeserializeOwned,
+{
+    let (resource, inner) = async_signal(Ok(default));
+    (resource, AsyncResultWriteSignal { inner })
+}
+
+impl<T, E> AsyncResultWriteSignal<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    /// Writes an error to the signal, which makes the paired resource yield
+    /// `Err`.
+    pub fn fail(&self, error: E) {
+        self.inner.set(Err(error));
+    }
+}
+
+impl<T, E> Set for AsyncResultWriteSignal<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    type Value = T;
+
+    fn set(&self, value: Self::Value) {
+        self.inner.set(Ok(value));
+    }
+
+    fn try_set(&self, value: Self::Value) -> Option<Self::Value> {
+        self.inner.try_set(Ok(value)).and_then(Result::ok)
+    }
+}
+
+impl<T, E> From<AsyncResultWriteSignal<T, E>> for AsyncWriteSignal<Result<T, E>> {
+    fn from(value: AsyncResultWriteSignal<T, E>) -> Self {
+        value.inner
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+#[cfg(feature = "ssr")]
+use futures::StreamExt;
+use leptos::prelude::*;
+
+use crate::{AsyncReadSignal, AsyncWriteSignal};
+
+/// An async write signal provided to a subtree of the component tree, which
+/// shadows the slot of the same type provided further up (if any), while still
+/// allowing to reach it. See [`provide_async_slot`].
+pub struct AsyncSlot<T>
+where
+    T: 'static,
+{
+    writer: AsyncWriteSignal<T>,
+    parent: Option<Arc<AsyncSlot<T>>>,
+}
+
+impl<T> Clone for AsyncSlot<T> {
+    fn clone(&self) -> Self {
+        Self { writer: self.writer.clone(), parent: self.parent.clone() }
+    }
+}
+
+impl<T> AsyncSlot<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Returns the async write signal of this slot.
+    pub fn writer(&self) -> AsyncWriteSignal<T> {
+        self.writer.clone()
+    }
+
+    /// Returns the slot shadowed by this one, if any.
+    pub fn parent(&self) -> Option<AsyncSlot<T>> {
+        self.parent.as_deref().cloned()
+    }
+}
+
+/// Provides the given async write signal as a slot to the current component
+/// and its descendants, and returns the slot. The slot shadows the slot of the
+/// same type provided by an ancestor, which stays reachable with
+/// [`AsyncSlot::parent`].
+///
+/// For example, a nested layout can provide its own breadcrumbs slot to its
+/// pages, and forward the final value to the enclosing slot with
+/// [`AsyncReadSignal::forward`]:
+///
+/// ```ignore
+/// let (crumbs_rx, crumbs_tx) = async_signal_split(Crumbs::default());
+/// let slot = provide_async_slot(crumbs_tx);
+/// if let Some(parent) = slot.parent() {
+///     crumbs_rx.forward(parent.writer(), |crumbs| crumbs.prefixed("Settings"));
+/// }
+/// ```
+pub fn provide_async_slot<T>(writer: AsyncWriteSignal<T>) -> AsyncSlot<T>
+where
+    T: Send + Sync + 'static,
+{
+    let parent = use_context::<AsyncSlot<T>>().map(Arc::new);
+    let slot = AsyncSlot { writer, parent };
+    provide_context(slot.clone());
+    slot
+}
+
+/// Returns the innermost async slot of the given type provided with
+/// [`provide_async_slot`], if any.
+pub fn use_async_slot<T>() -> Option<AsyncSlot<T>>
+where
+    T: Send + Sync + 'static,
+{
+    use_context::<AsyncSlot<T>>()
+}
+
+impl<T> AsyncReadSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Forwards the value of this signal to another async write signal, mapped
+    /// with `f`. The write signal is held until this signal is released.
+    ///
+    /// In SSR mode, the value is forwarded once it is ready (i.e. also when it
+    /// is the default one, as this signal was released without being written
+    /// to), and then upon each subsequent write. Hence, the other signal is
+    /// only ready once this one is. In other modes, the value is forwarded upon
+    /// each change, but not initially, so that a value hydrated from the server
+    /// is not replaced.
+    pub fn forward<U>(&self, to: AsyncWriteSignal<U>, f: impl Fn(&T) -> U + Send + Sync + 'static)
+    where
+        T: Clone,
+        U: Send + Sync + 'static,
+    {
+        #[cfg(feature = "ssr")]
+        {
+            let mut values = Box::pin(self.to_stream());
+            leptos::task::spawn(async move {
+                while let Some(value) = values.next().await {
+                    to.set(f(&value));
+                }
+            });
+        }
+        #[cfg(not(feature = "ssr"))]
+        {
+            let signal_read = self.signal_read.clone();
+            Effect::new(move |prev: Option<()>| {
+                let value = signal_read.get();
+                if let (Some(_), Some(value)) = (prev, value) {
+                    to.set(f(&value));
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use leptos::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::AsyncReadSignal;
+
+/// Renders the value of an async signal with `children`, through its reactive
+/// read path (see [`AsyncReadSignal::signal`]).
+///
+/// By default, the value is rendered within a `<Suspense/>`, which shows the
+/// fallback while the value is pending, i.e. in SSR mode until the value is
+/// ready, and in other modes after navigating (see
+/// `AsyncSignalBuilder::on_navigate` with the `router` feature). With
+/// `transition`, it is rendered within a `<Transition/>` instead, which only
+/// shows the fallback until the first value is ready, and keeps showing the
+/// previous value while the value is pending afterwards. This is usually what
+/// is wanted for elements that are written to by each page, such as
+/// breadcrumbs.
+///
+/// The paired resource is created by this component, so it should be rendered
+/// in the same place on both the server and the client.
+///
+/// ```ignore
+/// view! {
+///     <AsyncView signal=crumbs_rx transition=true let:crumbs>
+///         {crumbs.into_view()}
+///     </AsyncView>
+/// }
+/// ```
+#[component]
+pub fn AsyncView<T, C, V>(
+    /// The async signal to render.
+    signal: AsyncReadSignal<T>,
+    /// Whether the previous value is kept while the resource is pending.
+    #[prop(optional)]
+    transition: bool,
+    /// What to show before the first value is ready.
+    #[prop(optional, into)]
+    fallback: ViewFnOnce,
+    /// Renders the value of the signal.
+    children: C,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + Serialize + DeserializeOwned + 'static,
+    C: Fn(T) -> V + Send + Sync + 'static,
+    V: IntoView + 'static,
+{
+    let value = signal.signal();
+    let children = Arc::new(children);
+    let content = move || {
+        let children = children.clone();
+        value.get().map(move |value| children(value))
+    };
+    if transition {
+        view! { <Transition fallback>{content}</Transition> }.into_any()
+    } else {
+        view! { <Suspense fallback>{content}</Suspense> }.into_any()
+    }
+}
@@ -1,5 +1,12 @@
+use http::header::CACHE_CONTROL;
+use http::{HeaderValue, StatusCode};
 use leptos::prelude::*;
-use leptos_async_signal::{async_signal, AsyncWriteSignal};
+use leptos_async_signal::json_ld::{BreadcrumbList, JsonLd};
+use leptos_async_signal::meta::AsyncTitle;
+use leptos_async_signal::{
+    async_signal_split, bind_response, AsyncReadSignal, AsyncResponse, AsyncSignalBuilder,
+    AsyncSignalResultExt, AsyncView, AsyncWriteSignal, OnNavigate, ResponseMode,
+};
 use leptos_meta::{provide_meta_context, MetaTags, Title};
 use leptos_router::components::{Route, Router, Routes};
 use leptos_router::hooks::use_params;
@@ -34,17 +41,11 @@ pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
 
-    // Create async resource and signal.
-    let (crumbs_res, crumbs_tx) = async_signal(Crumbs::default());
-    // Provide the write side of the signal as context, so we don't have to pass it
-    // to each component.
-    provide_context(crumbs_tx);
-
     view! {
         <Router>
             <main>
-                // Create crumbs from the async signal's resource.
-                <Crumbs crumbs=crumbs_res />
+                // Create crumbs from the async signal.
+                <Crumbs />
                 <Routes fallback=|| "Page not found.".into_view()>
                     // NOTE: This all makes sense for SsrMode Async.
                     <Route path=path!("") ssr=SsrMode::Async view=HomePage />
@@ -57,7 +58,7 @@ pub fn App() -> impl IntoView {
 
 /// Crumbs are either for a home page or for a post page.
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
-enum Crumbs {
+pub enum Crumbs {
     #[default]
     Home,
     Post {
@@ -80,15 +81,47 @@ impl Crumbs {
             .into_any(),
         }
     }
+
+    /// Maps crumbs to structured data, for search engines.
+    fn structured_data(&self) -> BreadcrumbList {
+        let list = BreadcrumbList::default().item("Home", Some("/"));
+        match self {
+            Crumbs::Home => list,
+            Crumbs::Post { title } => list.item(title.clone(), None::<String>),
+        }
+    }
 }
 
 /// A component to show the crumbs. Use resource provided by async signal.
+///
+/// This needs to be rendered within the router, before the routes, as it
+/// provides the write side of the signal to them.
 #[component]
-fn Crumbs(crumbs: ArcResource<Crumbs>) -> impl IntoView {
+fn Crumbs() -> impl IntoView {
+    // On the server, the crumbs signal is request-scoped and provided by the axum
+    // integration (see `server.rs`). Otherwise, create the async signal here.
+    let crumbs_rx = use_context::<AsyncReadSignal<Crumbs>>().unwrap_or_else(|| {
+        // Wait for the next page to write the crumbs when navigating to it, while
+        // still showing the crumbs of the previous page in the meantime.
+        let (crumbs_rx, crumbs_tx) = AsyncSignalBuilder::new(Crumbs::default())
+            .on_navigate(OnNavigate::Transition)
+            .build_split();
+        // Provide the write side of the signal as context, so we don't have to pass
+        // it to each component.
+        provide_context(crumbs_tx);
+        crumbs_rx
+    });
+
+    let structured_data = crumbs_rx.map(Crumbs::structured_data);
+    // Keep showing the previous crumbs until the new ones are ready, instead of
+    // flashing back to the (empty) fallback.
     view! {
         <p>
-            <Suspense>{move || crumbs.get().unwrap_or_default().into_view()}</Suspense>
+            <AsyncView signal=crumbs_rx transition=true let:crumbs>
+                {crumbs.into_view()}
+            </AsyncView>
         </p>
+        <JsonLd signal=structured_data />
     }
 }
 
@@ -156,36 +189,60 @@ struct PostRequest {
 fn PostPage() -> impl IntoView {
     let crumbs = use_context::<AsyncWriteSignal<Crumbs>>().unwrap();
     let params = use_params::<PostRequest>();
+    // The response status is only known once the post is fetched, so delay the
+    // response head until then.
+    let (status_rx, status_tx) = async_signal_split(StatusCode::OK);
+    bind_response(&status_rx, ResponseMode::Blocking, |status| {
+        let cache_control = match status.is_success() {
+            true => HeaderValue::from_static("public, max-age=60"),
+            false => HeaderValue::from_static("no-store"),
+        };
+        AsyncResponse::default()
+            .status(*status)
+            .header(CACHE_CONTROL, cache_control)
+    });
+    // The title is set in <head> only once the post is fetched.
+    let (title_rx, title_tx) = async_signal_split(String::new());
     let post = Resource::new(
         move || params.read().as_ref().ok().and_then(|pid| pid.id),
         move |post_id| {
-            let crumbs = crumbs.clone();
+            // Set crumbs to the post once fetched, or fall back to home on error.
+            // Note: crumbs need to be set here, and not in the Suspend, as otherwise
+            // there is a deadlock between two Suspends.
+            // Superseding makes sure that a slow response for a previous post (or
+            // after leaving the page) does not overwrite the current value.
+            let crumbs = crumbs.supersede();
+            let status_tx = status_tx.supersede();
+            let title_tx = title_tx.supersede();
+            let post_res = post_id.map(|id| {
+                post_by_id(id)
+                    .set_signal(
+                        crumbs,
+                        |post| Crumbs::Post { title: post.title.clone() },
+                        |_| Crumbs::Home,
+                    )
+                    .set_signal(status_tx.clone(), |_| StatusCode::OK, |_| StatusCode::NOT_FOUND)
+                    .set_signal(
+                        title_tx.clone(),
+                        |post| post.title.clone(),
+                        |_| "Post not found".to_string(),
+                    )
+            });
             async move {
-                match post_id {
-                    Some(id) => {
-                        let post_res = post_by_id(id).await;
-
-                        // Set crumbs to the post, once fetched.
-                        // Note: crumbs need to be set here, and not in the Suspend, as otherwise
-                        // there is a deadlock between two Suspends.
-                        match &post_res {
-                            Ok(post) => {
-                                crumbs.set(Crumbs::Post { title: post.title.clone() });
-                            }
-                            Err(_) => {
-                                crumbs.set(Crumbs::Home);
-                            }
-                        }
-
-                        post_res.map_err(|err| err.to_string())
+                match post_res {
+                    Some(post_res) => post_res.await.map_err(|err| err.to_string()),
+                    None => {
+                        status_tx.set(StatusCode::NOT_FOUND);
+                        title_tx.set("Post not found".to_string());
+                        Err("Invalid URL".to_string())
                     }
-                    None => Err("Invalid URL".to_string()),
                 }
             }
         },
     );
 
     view! {
+        <AsyncTitle signal=title_rx />
         <Suspense>
             { move || Suspend::new({
                 // let crumbs = crumbs.clone();
@@ -204,7 +261,6 @@ fn PostPage() -> impl IntoView {
                                 })
                                 .collect_view();
                             view! {
-                                <Title text=post.title.clone() />
                                 <h1>{post.title}</h1>
                                 {body}
                             }
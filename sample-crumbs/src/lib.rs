@@ -3,6 +3,8 @@ pub mod app;
 #[cfg(feature = "ssr")]
 pub mod db;
 pub mod model;
+#[cfg(feature = "ssr")]
+pub mod server;
 
 #[cfg(feature = "hydrate")]
 #[wasm_bindgen::prelude::wasm_bindgen]
@@ -0,0 +1,49 @@
+//! The axum server setup.
+
+use axum::extract::Request;
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::Router;
+use http::HeaderValue;
+use leptos::prelude::*;
+use leptos_async_signal::axum::{provide_request_signal, RequestSignal};
+use leptos_axum::{generate_route_list, LeptosRoutes};
+
+use crate::app::{shell, App, Crumbs};
+
+/// Builds the application router.
+pub fn router(leptos_options: LeptosOptions) -> Router {
+    // Generate the list of routes in your Leptos App
+    let routes = generate_route_list(App);
+
+    Router::new()
+        .leptos_routes_with_context(
+            &leptos_options,
+            routes,
+            // Provide the request-scoped crumbs signal to the app.
+            || _ = provide_request_signal::<Crumbs>(),
+            {
+                let leptos_options = leptos_options.clone();
+                move || shell(leptos_options.clone())
+            },
+        )
+        .fallback(leptos_axum::file_and_error_handler(shell))
+        .layer(middleware::from_fn(crumbs))
+        .with_state(leptos_options)
+}
+
+/// A middleware that creates the request-scoped crumbs signal, and exposes its
+/// final value in the `x-crumbs` response header.
+async fn crumbs(request: Request, next: Next) -> Response {
+    let crumbs = RequestSignal::new(Crumbs::default());
+    let mut response = crumbs.run(request, next).await;
+    // All routes are rendered with `SsrMode::Async`, so the crumbs are final here.
+    let value = match crumbs.get() {
+        Crumbs::Home => HeaderValue::from_static("Home"),
+        Crumbs::Post { title } => {
+            HeaderValue::from_str(&title).unwrap_or(HeaderValue::from_static("Post"))
+        }
+    };
+    response.headers_mut().insert("x-crumbs", value);
+    response
+}
@@ -0,0 +1,47 @@
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::response::Response;
+use leptos::prelude::*;
+use sample_crumbs::server::router;
+use tower::ServiceExt;
+
+async fn get(uri: &str) -> Response {
+    let options = LeptosOptions::builder()
+        .output_name("sample-crumbs")
+        .build();
+    router(options)
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn body(response: Response) -> String {
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn home_crumbs() {
+    let response = get("/").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-crumbs"], "Home");
+    assert!(body(response).await.contains("First post"));
+}
+
+#[tokio::test]
+async fn post_crumbs() {
+    let response = get("/post/2").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-crumbs"], "Second post");
+    assert_eq!(response.headers()["cache-control"], "public, max-age=60");
+    assert!(body(response).await.contains("It even has another line."));
+}
+
+#[tokio::test]
+async fn missing_post() {
+    let response = get("/post/7").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()["x-crumbs"], "Home");
+    assert_eq!(response.headers()["cache-control"], "no-store");
+    assert!(body(response).await.contains("Post not found: 7"));
+}
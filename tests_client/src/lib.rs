@@ -0,0 +1,130 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use hydration_context::{PinnedFuture, PinnedStream, SerializedDataId, SharedContext};
+use leptos::error::{Error, ErrorId};
+use leptos::prelude::*;
+use leptos_router::location::{Location, State};
+use tokio::task::LocalSet;
+
+/// Sets up a reactive owner, as on the client. The returned owner needs to be
+/// kept alive for the context provided within it to be used.
+pub fn init_test() -> Owner {
+    // Set async executor (once per test binary, as tests may run in parallel)
+    let _ = any_spawner::Executor::init_tokio();
+
+    let owner = Owner::new();
+    owner.set();
+    owner
+}
+
+/// Same as [`init_test`], but also sets a shared context as while hydrating,
+/// from which resources read the given serialized values, in the order they
+/// are created. The returned owner needs to be kept alive for the shared
+/// context to be used.
+pub fn init_test_hydration(data: &[&str]) -> Owner {
+    let _ = any_spawner::Executor::init_tokio();
+
+    let shared_context = HydrationContext {
+        data: data.iter().map(|data| data.to_string()).collect(),
+        next_id: AtomicUsize::new(0),
+    };
+    let owner = Owner::new_root(Some(Arc::new(shared_context)));
+    owner.set();
+    owner
+}
+
+/// Runs the given future on a local set, which is needed to run effects.
+pub async fn run_local<F: Future>(fut: F) -> F::Output {
+    LocalSet::new().run_until(fut).await
+}
+
+/// Waits for effects and resources to run.
+pub async fn tick() {
+    tokio::time::sleep(Duration::from_millis(5)).await;
+}
+
+/// Provides a location whose path can be changed, as the router would on the
+/// client.
+pub fn provide_location() -> RwSignal<String> {
+    let path = RwSignal::new("/a".to_string());
+    provide_context(Location {
+        pathname: Memo::new(move |_| path.get()),
+        search: Memo::new(|_| String::new()),
+        query: Memo::new(|_| Default::default()),
+        hash: Memo::new(|_| String::new()),
+        state: RwSignal::new(State::default()).read_only(),
+    });
+    path
+}
+
+/// A shared context holding the data serialized by the server, as the browser
+/// does while hydrating.
+#[derive(Debug)]
+struct HydrationContext {
+    data: Vec<String>,
+    next_id: AtomicUsize,
+}
+
+impl SharedContext for HydrationContext {
+    fn is_browser(&self) -> bool {
+        true
+    }
+
+    fn next_id(&self) -> SerializedDataId {
+        SerializedDataId::new(self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn write_async(&self, _id: SerializedDataId, _fut: PinnedFuture<String>) {}
+
+    fn read_data(&self, id: &SerializedDataId) -> Option<String> {
+        self.data.get(id.clone().into_inner()).cloned()
+    }
+
+    fn await_data(&self, id: &SerializedDataId) -> Option<String> {
+        self.read_data(id)
+    }
+
+    fn pending_data(&self) -> Option<PinnedStream<String>> {
+        None
+    }
+
+    fn during_hydration(&self) -> bool {
+        true
+    }
+
+    fn hydration_complete(&self) {}
+
+    fn get_is_hydrating(&self) -> bool {
+        false
+    }
+
+    fn set_is_hydrating(&self, _is_hydrating: bool) {}
+
+    fn take_errors(&self) -> Vec<(SerializedDataId, ErrorId, Error)> {
+        Vec::new()
+    }
+
+    fn errors(&self, _boundary_id: &SerializedDataId) -> Vec<(ErrorId, Error)> {
+        Vec::new()
+    }
+
+    fn seal_errors(&self, _boundary_id: &SerializedDataId) {}
+
+    fn register_error(&self, _error_boundary: SerializedDataId, _error_id: ErrorId, _error: Error) {
+    }
+
+    fn defer_stream(&self, _wait_for: PinnedFuture<()>) {}
+
+    fn await_deferred(&self) -> Option<PinnedFuture<()>> {
+        None
+    }
+
+    fn set_incomplete_chunk(&self, _id: SerializedDataId) {}
+
+    fn get_incomplete_chunk(&self, _id: &SerializedDataId) -> bool {
+        false
+    }
+}
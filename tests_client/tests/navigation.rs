@@ -0,0 +1,133 @@
+use std::future::IntoFuture;
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_client::{init_test, provide_location, run_local, tick};
+
+/// Returns the value of the resource, or `None` if it is pending.
+async fn value<T>(resource: &ArcResource<T>) -> Option<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    tokio::time::timeout(Duration::from_millis(20), resource.clone().into_future())
+        .await
+        .ok()
+}
+
+async fn navigate(on_navigate: OnNavigate, write_before_effects: bool) -> (Option<i32>, i32) {
+    let _owner = init_test();
+    let path = provide_location();
+    let (resource, tx) = AsyncSignalBuilder::new(0).on_navigate(on_navigate).build();
+    tx.set(1);
+    tick().await;
+    assert_eq!(value(&resource).await, Some(1));
+
+    path.set("/b".to_string());
+    if write_before_effects {
+        tx.set(3);
+    }
+    tick().await;
+    let after_navigation = value(&resource).await;
+    tx.set(2);
+    tick().await;
+    (after_navigation, value(&resource).await.unwrap())
+}
+
+#[tokio::test]
+async fn keep() {
+    let (after_navigation, after_write) = run_local(navigate(OnNavigate::Keep, false)).await;
+    assert_eq!(after_navigation, Some(1));
+    assert_eq!(after_write, 2);
+}
+
+#[tokio::test]
+async fn reset_is_pending_until_written() {
+    let (after_navigation, after_write) = run_local(navigate(OnNavigate::Reset, false)).await;
+    assert_eq!(after_navigation, None);
+    assert_eq!(after_write, 2);
+}
+
+#[tokio::test]
+async fn transition_is_pending_until_written() {
+    let (after_navigation, after_write) = run_local(navigate(OnNavigate::Transition, false)).await;
+    assert_eq!(after_navigation, None);
+    assert_eq!(after_write, 2);
+}
+
+#[tokio::test]
+async fn write_before_reset_is_kept() {
+    let (after_navigation, after_write) = run_local(navigate(OnNavigate::Reset, true)).await;
+    assert_eq!(after_navigation, Some(3));
+    assert_eq!(after_write, 2);
+}
+
+async fn navigate_back(on_navigate: OnNavigate) -> (Option<i32>, i32) {
+    let _owner = init_test();
+    let path = provide_location();
+    let (resource, tx) = AsyncSignalBuilder::new(0).on_navigate(on_navigate).build();
+    tx.set(1);
+    tick().await;
+    path.set("/b".to_string());
+    tick().await;
+    tx.set(2);
+    tick().await;
+    assert_eq!(value(&resource).await, Some(2));
+
+    // Back at the path written to first, the page writes again.
+    path.set("/a".to_string());
+    tick().await;
+    let after_navigation = value(&resource).await;
+    tx.set(3);
+    tick().await;
+    (after_navigation, value(&resource).await.unwrap())
+}
+
+#[tokio::test]
+async fn reset_back_is_pending_until_written() {
+    let (after_navigation, after_write) = run_local(navigate_back(OnNavigate::Reset)).await;
+    assert_eq!(after_navigation, None);
+    assert_eq!(after_write, 3);
+}
+
+#[tokio::test]
+async fn transition_back_is_pending_until_written() {
+    let (after_navigation, after_write) = run_local(navigate_back(OnNavigate::Transition)).await;
+    assert_eq!(after_navigation, None);
+    assert_eq!(after_write, 3);
+}
+
+async fn navigate_join(on_navigate: OnNavigate) -> (Option<(i32, i32)>, (i32, i32)) {
+    let _owner = init_test();
+    let path = provide_location();
+    let (a_rx, a_tx) = AsyncSignalBuilder::new(0)
+        .on_navigate(on_navigate)
+        .build_split();
+    let (b_rx, b_tx) = async_signal_split(0);
+    let resource = join_async((a_rx, b_rx)).resource();
+    a_tx.set(1);
+    b_tx.set(1);
+    tick().await;
+    assert_eq!(value(&resource).await, Some((1, 1)));
+
+    path.set("/b".to_string());
+    tick().await;
+    let after_navigation = value(&resource).await;
+    a_tx.set(2);
+    tick().await;
+    (after_navigation, value(&resource).await.unwrap())
+}
+
+#[tokio::test]
+async fn join_reset_is_pending_until_written() {
+    let (after_navigation, after_write) = run_local(navigate_join(OnNavigate::Reset)).await;
+    assert_eq!(after_navigation, None);
+    assert_eq!(after_write, (2, 1));
+}
+
+#[tokio::test]
+async fn join_transition_is_pending_until_written() {
+    let (after_navigation, after_write) = run_local(navigate_join(OnNavigate::Transition)).await;
+    assert_eq!(after_navigation, None);
+    assert_eq!(after_write, (2, 1));
+}
@@ -0,0 +1,48 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_client::{init_test, run_local, tick};
+
+/// Counts how many times the resource yields a new value.
+fn count_loads(resource: ArcResource<i32>) -> Arc<AtomicUsize> {
+    let loads = Arc::new(AtomicUsize::new(0));
+    Effect::new({
+        let loads = loads.clone();
+        move || {
+            if resource.get().is_some() {
+                loads.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    });
+    loads
+}
+
+#[tokio::test]
+async fn equal_writes_skip_refetch() {
+    run_local(async {
+        let _owner = init_test();
+        let (resource, tx) = async_signal(0);
+        let loads = count_loads(resource.clone());
+        assert!(tx.set_if_changed(1));
+        tick().await;
+        assert_eq!(resource.get_untracked(), Some(1));
+        let after_first = loads.load(Ordering::Relaxed);
+
+        assert!(!tx.set_if_changed(1));
+        tick().await;
+        assert_eq!(loads.load(Ordering::Relaxed), after_first);
+
+        // A regular write still re-runs the resource.
+        tx.set(1);
+        tick().await;
+        assert_eq!(loads.load(Ordering::Relaxed), after_first + 1);
+
+        assert!(tx.set_if_changed(2));
+        tick().await;
+        assert_eq!(loads.load(Ordering::Relaxed), after_first + 2);
+        assert_eq!(resource.get_untracked(), Some(2));
+    })
+    .await;
+}
@@ -0,0 +1,27 @@
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_client::{init_test_hydration, run_local, tick};
+
+#[tokio::test]
+async fn hydrated_value_until_written() {
+    run_local(async {
+        // The server rendered the page with the title written there.
+        let _owner = init_test_hydration(&["\"Hello world\""]);
+        let (title_rx, title_tx) = async_signal_split("Untitled".to_string());
+        let title = title_rx.signal();
+
+        // The hydrated value is used, although the signal itself was not
+        // written to on the client yet.
+        assert_eq!(title.get_untracked().as_deref(), Some("Hello world"));
+
+        // A client write is read right away, i.e. from the signal rather than
+        // from the resource, which would only resolve a re-run asynchronously.
+        title_tx.set("Hello client".to_string());
+        assert_eq!(title.get_untracked().as_deref(), Some("Hello client"));
+        title_tx.set("Hello again".to_string());
+        assert_eq!(title.get_untracked().as_deref(), Some("Hello again"));
+        tick().await;
+        assert_eq!(title.get_untracked().as_deref(), Some("Hello again"));
+    })
+    .await;
+}
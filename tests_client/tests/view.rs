@@ -0,0 +1,70 @@
+use std::sync::{Arc, Mutex};
+
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use reactive_graph::computed::suspense::SuspenseContext;
+use tests_client::{init_test, provide_location, run_local, tick};
+
+/// What a `<Transition/>` sees of its children: whether any resource read
+/// within it is pending, and what the children render. Views cannot be mounted
+/// outside of a browser, so this reads the value the way `<AsyncView/>` does,
+/// within a suspense context.
+struct Rendered {
+    _owner: Owner,
+    suspense: SuspenseContext,
+    value: Arc<Mutex<Option<String>>>,
+}
+
+impl Rendered {
+    fn new(signal: &AsyncReadSignal<String>) -> Self {
+        let suspense = SuspenseContext { tasks: ArcRwSignal::new(Default::default()) };
+        let value = Arc::new(Mutex::new(None));
+        let owner = Owner::new();
+        owner.with(|| {
+            provide_context(suspense.clone());
+            let signal = signal.signal();
+            let value = value.clone();
+            Effect::new(move || *value.lock().unwrap() = signal.get());
+        });
+        Self { _owner: owner, suspense, value }
+    }
+
+    fn pending(&self) -> bool {
+        !self.suspense.tasks.with_untracked(|tasks| tasks.is_empty())
+    }
+
+    fn value(&self) -> Option<String> {
+        self.value.lock().unwrap().clone()
+    }
+}
+
+#[tokio::test]
+async fn transition_keeps_previous_value_while_pending() {
+    run_local(async {
+        let _owner = init_test();
+        let path = provide_location();
+        let (title_rx, title_tx) = AsyncSignalBuilder::new("Untitled".to_string())
+            .on_navigate(OnNavigate::Transition)
+            .build_split();
+        let rendered = Rendered::new(&title_rx);
+        title_tx.set("First".to_string());
+        tick().await;
+        assert!(!rendered.pending());
+        assert_eq!(rendered.value().as_deref(), Some("First"));
+
+        // After navigating, the children are pending, but still render the
+        // previous value, which a transition keeps showing (instead of its
+        // fallback) once it has rendered a value.
+        path.set("/b".to_string());
+        tick().await;
+        assert!(rendered.pending());
+        assert_eq!(rendered.value().as_deref(), Some("First"));
+
+        // Once the new page writes, the new value is rendered.
+        title_tx.set("Second".to_string());
+        tick().await;
+        assert!(!rendered.pending());
+        assert_eq!(rendered.value().as_deref(), Some("Second"));
+    })
+    .await;
+}
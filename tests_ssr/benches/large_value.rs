@@ -0,0 +1,58 @@
+//! Renders a page whose async signal holds a large value, which is written to
+//! several times before it is rendered.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test_hydration;
+
+const NODES: usize = 10_000;
+const WRITES: usize = 8;
+
+fn tree(size: usize) -> Vec<String> {
+    (0..size).map(|i| format!("/section/{i}/page")).collect()
+}
+
+#[component]
+fn App() -> impl IntoView {
+    let (tree_res, tree_tx) = async_signal(Vec::<String>::new());
+    view! {
+        <Suspense>{move || tree_res.get().map(|tree| tree.len())}</Suspense>
+        <Writer tree_tx />
+    }
+}
+
+#[component]
+fn Writer(tree_tx: AsyncWriteSignal<Vec<String>>) -> impl IntoView {
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let tree_tx = tree_tx.clone();
+            async move {
+                for size in (1..=WRITES).map(|i| i * NODES / WRITES) {
+                    tree_tx.set(tree(size));
+                    tokio::task::yield_now().await;
+                }
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get().map(|_| "done")}</Suspense> }
+}
+
+fn render(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    c.bench_function("render large value", |b| {
+        b.to_async(&runtime).iter_batched(
+            init_test_hydration,
+            |(_owner, _)| async move {
+                let app = view! { <App /> };
+                app.to_html_stream_in_order().collect::<String>().await
+            },
+            BatchSize::PerIteration,
+        )
+    });
+}
+
+criterion_group!(benches, render);
+criterion_main!(benches);
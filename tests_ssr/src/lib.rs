@@ -1,13 +1,30 @@
+use std::sync::Arc;
+
+use hydration_context::SsrSharedContext;
+use reactive_graph::owner::Owner;
+
 pub async fn fetch_data() -> (String, String) {
     tokio::time::sleep(std::time::Duration::from_millis(1)).await;
     ("Hello world".to_string(), "42".to_string())
 }
 
 pub fn init_test() {
-    // Set async executor
-    any_spawner::Executor::init_tokio().unwrap();
+    // Set async executor (once per test binary, as tests may run in parallel)
+    let _ = any_spawner::Executor::init_tokio();
 
     // This sets sandbox arena for reactive graph
-    let owner = reactive_graph::owner::Owner::new();
+    let owner = Owner::new();
+    owner.set();
+}
+
+/// Same as [`init_test`], but also sets a shared context that collects the
+/// data serialized for hydration, such as resource values. The returned owner
+/// needs to be kept alive for the shared context to be used.
+pub fn init_test_hydration() -> (Owner, Arc<SsrSharedContext>) {
+    let _ = any_spawner::Executor::init_tokio();
+
+    let shared_context = Arc::new(SsrSharedContext::new());
+    let owner = Owner::new_root(Some(shared_context.clone()));
     owner.set();
+    (owner, shared_context)
 }
@@ -0,0 +1,123 @@
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::http::StatusCode;
+use actix_web::middleware::{self, Next};
+use actix_web::{test, Error};
+use leptos::prelude::*;
+use leptos_actix::{generate_route_list, LeptosRoutes};
+use leptos_async_signal::actix::{provide_request_signal, RequestSignal};
+use leptos_async_signal::*;
+use leptos_router::components::{Route, Router, Routes};
+use leptos_router::{path, SsrMode};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Title(String);
+
+/// The value of the request signal, awaited in a task spawned by the
+/// middleware.
+struct AwaitedTitle(tokio::task::JoinHandle<Title>);
+
+fn take_awaited_title(
+    response: &mut ServiceResponse<impl MessageBody>,
+) -> tokio::task::JoinHandle<Title> {
+    let AwaitedTitle(value) = response.response_mut().extensions_mut().remove().unwrap();
+    value
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <Routes fallback=|| "Not found">
+                <Route path=path!("") ssr=SsrMode::Async view=Page />
+                <Route path=path!("other") ssr=SsrMode::Async view=|| "Other" />
+            </Routes>
+        </Router>
+    }
+}
+
+#[component]
+fn Page() -> impl IntoView {
+    let title_tx = use_context::<AsyncWriteSignal<Title>>().unwrap();
+    let title_rx = use_context::<AsyncReadSignal<Title>>().unwrap();
+    bind_response(&title_rx, ResponseMode::Blocking, |title| {
+        AsyncResponse::default()
+            .status(http::StatusCode::NON_AUTHORITATIVE_INFORMATION)
+            .header(
+                http::HeaderName::from_static("x-title"),
+                http::HeaderValue::from_str(&title.0).unwrap(),
+            )
+    });
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let title_tx = title_tx.clone();
+            async move {
+                let (title, _) = tests_ssr::fetch_data().await;
+                title_tx.set(Title(title.clone()));
+                title
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get()}</Suspense> }
+}
+
+async fn title(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let title = RequestSignal::new(Title("seeded".to_string()));
+    // Awaiting the value in a task spawned before rendering must not take the
+    // writer away from the rendering.
+    let value = tokio::spawn({
+        let title = title.clone();
+        async move { title.value().await }
+    });
+    tokio::task::yield_now().await;
+    let mut response = title.run_service(request, next).await?;
+    response.headers_mut().insert(
+        HeaderName::from_static("x-final-title"),
+        HeaderValue::from_str(&title.get().0).unwrap(),
+    );
+    // The value may only be ready once the body is streamed.
+    response
+        .response_mut()
+        .extensions_mut()
+        .insert(AwaitedTitle(value));
+    Ok(response)
+}
+
+async fn get(uri: &str) -> ServiceResponse<impl MessageBody> {
+    let routes = generate_route_list(App);
+    let app = test::init_service(
+        actix_web::App::new()
+            .leptos_routes_with_context(routes, || _ = provide_request_signal::<Title>(), App)
+            .wrap(middleware::from_fn(title)),
+    )
+    .await;
+    test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await
+}
+
+#[actix_web::test]
+async fn request_signal() {
+    let mut response = get("/").await;
+    assert_eq!(response.status(), StatusCode::NON_AUTHORITATIVE_INFORMATION);
+    assert_eq!(response.headers().get("x-title").unwrap(), "Hello world");
+    assert_eq!(response.headers().get("x-final-title").unwrap(), "Hello world");
+    let value = take_awaited_title(&mut response);
+    let body = test::read_body(response).await;
+    assert!(String::from_utf8_lossy(&body).contains("Hello world"));
+    assert_eq!(value.await.unwrap(), Title("Hello world".to_string()));
+}
+
+#[actix_web::test]
+async fn request_signal_seeded() {
+    let mut response = get("/other").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("x-title").is_none());
+    assert_eq!(response.headers().get("x-final-title").unwrap(), "seeded");
+    let value = take_awaited_title(&mut response);
+    test::read_body(response).await;
+    assert_eq!(value.await.unwrap(), Title("seeded".to_string()));
+}
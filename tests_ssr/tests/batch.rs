@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use futures::{FutureExt, StreamExt};
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test_hydration;
+use tokio::time::timeout;
+
+#[component]
+pub fn App(crumbs_res: ArcResource<String>, title_res: ArcResource<String>) -> impl IntoView {
+    view! {
+        <Suspense>{move || crumbs_res.get().map(|crumbs| format!("crumbs: {crumbs}"))}</Suspense>
+        <Suspense>{move || title_res.get().map(|title| format!("title: {title}"))}</Suspense>
+    }
+}
+
+#[tokio::test]
+async fn batch_resolves_together() {
+    let (_owner, _) = init_test_hydration();
+    let (crumbs_rx, crumbs_tx) = async_signal_split("no crumbs".to_string());
+    let (title_rx, title_tx) = async_signal_split("no title".to_string());
+    let (crumbs_res, title_res) = (crumbs_rx.resource(), title_rx.resource());
+    let app = view! { <App crumbs_res title_res /> };
+    let chunks = tokio::spawn(app.to_html_stream_out_of_order().collect::<Vec<_>>());
+    let mut crumbs = Box::pin(crumbs_rx.to_stream());
+    batch_async(|| {
+        crumbs_tx.set("Home / Post".to_string());
+        // Without batching, the crumbs would be ready (and streamed) already.
+        assert!(crumbs.next().now_or_never().is_none());
+        title_tx.set("Post title".to_string());
+    });
+    assert_eq!(crumbs.next().now_or_never().flatten().as_deref(), Some("Home / Post"));
+    let chunks = timeout(Duration::from_secs(1), chunks)
+        .await
+        .expect("SSR should not have timed out")
+        .unwrap();
+    let crumbs = chunks
+        .iter()
+        .position(|chunk| chunk.contains("crumbs: Home / Post"))
+        .expect("crumbs should be streamed");
+    assert!(chunks[crumbs].contains("title: Post title"), "{chunks:#?}");
+}
+
+#[tokio::test]
+async fn batch_defers_readiness() {
+    let (_owner, _) = init_test_hydration();
+    let (crumbs_rx, crumbs_tx) = async_signal_split("no crumbs".to_string());
+    let (title_rx, title_tx) = async_signal_split("no title".to_string());
+    let mut crumbs = Box::pin(crumbs_rx.to_stream());
+    let mut title = Box::pin(title_rx.to_stream());
+    batch_async(|| {
+        crumbs_tx.set("Home / Post".to_string());
+        // Nested batches are part of the outer one.
+        batch_async(|| title_tx.set("Post title".to_string()));
+        assert!(crumbs.next().now_or_never().is_none());
+        assert!(title.next().now_or_never().is_none());
+    });
+    assert_eq!(crumbs.next().now_or_never().flatten().as_deref(), Some("Home / Post"));
+    assert_eq!(title.next().now_or_never().flatten().as_deref(), Some("Post title"));
+}
@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::breadcrumbs::{use_crumb, BreadcrumbTrail, BreadcrumbTrailEnd};
+use leptos_router::components::{Outlet, ParentRoute, Route, Router, Routes};
+use leptos_router::hooks::use_params_map;
+use leptos_router::location::RequestUrl;
+use leptos_router::path;
+use tests_ssr::init_test_hydration;
+
+/// Resolves the label after the given delay, so that crumbs resolve out of
+/// order.
+async fn label(label: impl ToString, delay: u64) -> String {
+    tokio::time::sleep(Duration::from_millis(delay)).await;
+    label.to_string()
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <BreadcrumbTrail />
+            <Routes fallback=|| "Not found">
+                <ParentRoute path=path!("") view=Root>
+                    <ParentRoute path=path!("docs") view=Docs>
+                        <Route path=path!(":page") view=Page />
+                        <Route path=path!("") view=|| "Docs index" />
+                    </ParentRoute>
+                    <Route path=path!("plain/x") view=|| "Plain page" />
+                    <Route path=path!("") view=|| "Home page" />
+                </ParentRoute>
+            </Routes>
+            <BreadcrumbTrailEnd />
+        </Router>
+    }
+}
+
+#[component]
+fn Root() -> impl IntoView {
+    use_crumb(label("Home", 10));
+    view! { <Outlet /> }
+}
+
+#[component]
+fn Docs() -> impl IntoView {
+    use_crumb(label("Docs", 5));
+    view! { <Outlet /> }
+}
+
+#[component]
+fn Page() -> impl IntoView {
+    let page = use_params_map()
+        .get_untracked()
+        .get("page")
+        .unwrap_or_default();
+    use_crumb(label(format!("Page {page}"), 1));
+    view! { <p>{format!("Page body of {page}")}</p> }
+}
+
+async fn render(path: &str) -> String {
+    let (_owner, _) = init_test_hydration();
+    provide_context(RequestUrl::new(path));
+    let app = view! { <App /> };
+    app.to_html_stream_in_order().collect::<String>().await
+}
+
+/// Renders with out-of-order streaming, where the trail is streamed once it
+/// resolves, possibly before the routes finish streaming.
+async fn render_out_of_order(path: &str) -> String {
+    let (_owner, _) = init_test_hydration();
+    provide_context(RequestUrl::new(path));
+    let app = view! { <App /> };
+    let html = app.to_html_stream_out_of_order().collect::<String>();
+    tokio::time::timeout(Duration::from_secs(2), html)
+        .await
+        .expect("trail resolves")
+}
+
+#[tokio::test]
+async fn nested_trail() {
+    let html = render("/docs/intro").await;
+    assert!(html.contains("Page body of intro"));
+    let trail = concat!(
+        r#"<li><a href="/">Home</a></li>"#,
+        r#"<li><a href="/docs">Docs</a></li>"#,
+        r#"<li><span aria-current="page">Page intro</span></li>"#,
+    );
+    assert!(html.contains(trail), "{html}");
+}
+
+#[tokio::test]
+async fn parent_trail() {
+    let html = render("/docs").await;
+    assert!(html.contains("Docs index"));
+    let trail = concat!(
+        r#"<li><a href="/">Home</a></li>"#,
+        r#"<li><span aria-current="page">Docs</span></li>"#,
+    );
+    assert!(html.contains(trail), "{html}");
+}
+
+#[tokio::test]
+async fn root_trail() {
+    let html = render("/").await;
+    assert!(html.contains("Home page"));
+    assert!(html.contains(r#"<li><span aria-current="page">Home</span></li>"#), "{html}");
+}
+
+/// Renders with a timeout, for paths where the trail has no crumb for the full
+/// path, and hence only resolves once the routes are rendered.
+async fn render_settled(path: &str) -> String {
+    tokio::time::timeout(Duration::from_secs(2), render(path))
+        .await
+        .expect("trail resolves")
+}
+
+#[tokio::test]
+async fn leaf_without_crumb() {
+    let html = render_settled("/plain/x").await;
+    assert!(html.contains("Plain page"));
+    assert!(html.contains(r#"<li><span aria-current="page">Home</span></li>"#), "{html}");
+}
+
+#[tokio::test]
+async fn not_found() {
+    let html = render_settled("/nope/nope").await;
+    assert!(html.contains("Not found"));
+    assert!(!html.contains("<li>"), "{html}");
+}
+
+#[tokio::test]
+async fn nested_trail_out_of_order() {
+    let html = render_out_of_order("/docs/intro").await;
+    assert!(html.contains("Page body of intro"));
+    let trail = concat!(
+        r#"<li><a href="/">Home</a></li>"#,
+        r#"<li><a href="/docs">Docs</a></li>"#,
+        r#"<li><span aria-current="page">Page intro</span></li>"#,
+    );
+    assert!(html.contains(trail), "{html}");
+}
+
+#[tokio::test]
+async fn leaf_without_crumb_out_of_order() {
+    let html = render_out_of_order("/docs").await;
+    assert!(html.contains("Docs index"));
+    let trail = concat!(
+        r#"<li><a href="/">Home</a></li>"#,
+        r#"<li><span aria-current="page">Docs</span></li>"#,
+    );
+    assert!(html.contains(trail), "{html}");
+
+    let html = render_out_of_order("/plain/x").await;
+    assert!(html.contains("Plain page"));
+    assert!(html.contains(r#"<li><span aria-current="page">Home</span></li>"#), "{html}");
+}
+
+#[tokio::test]
+async fn not_found_out_of_order() {
+    let html = render_out_of_order("/nope/nope").await;
+    assert!(html.contains("Not found"));
+    assert!(!html.contains("<li>"), "{html}");
+}
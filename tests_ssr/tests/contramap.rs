@@ -0,0 +1,102 @@
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use serde::{Deserialize, Serialize};
+use tests_ssr::init_test;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+struct Header {
+    title: String,
+    count: u32,
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (header_res, header_tx) = async_signal(Header::default());
+    view! {
+        <Suspense>
+            { move || {
+                let msg = match header_res.get() {
+                    None => "no header yet".to_owned(),
+                    Some(Header { title, count }) => format!("header is: {title} ({count})")
+                };
+                view! { <span id="msg">{msg}</span> }
+            }
+        }
+        </Suspense>
+        // Unused mapped signal is dropped right away, which must not release the header.
+        <Unused tx=header_tx.contramap(|title| Header { title, count: 0 }) />
+        <Title title_tx=header_tx.contramap(|title| Header { title, count: 1 }) />
+    }
+}
+
+#[component]
+fn Unused(tx: AsyncWriteSignal<String>) -> impl IntoView {
+    drop(tx);
+}
+
+#[component]
+fn Title(title_tx: AsyncWriteSignal<String>) -> impl IntoView {
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let title_tx = title_tx.clone();
+            async move {
+                let (msg, _) = tests_ssr::fetch_data().await;
+                title_tx.set(msg);
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get().map(|_| "done")}</Suspense> }
+}
+
+#[component]
+pub fn LensApp() -> impl IntoView {
+    let (header_res, header_tx) = async_signal(Header { title: "default".to_string(), count: 0 });
+    let count_tx = header_tx.lens(|header: &mut Header| &mut header.count);
+    drop(header_tx);
+    view! {
+        <Suspense>
+            { move || {
+                let msg = match header_res.get() {
+                    None => "no header yet".to_owned(),
+                    Some(Header { title, count }) => format!("header is: {title} ({count})")
+                };
+                view! { <span id="msg">{msg}</span> }
+            }
+        }
+        </Suspense>
+        <Count count_tx />
+    }
+}
+
+#[component]
+fn Count(count_tx: AsyncWriteSignal<u32>) -> impl IntoView {
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let count_tx = count_tx.clone();
+            async move {
+                let (_, num) = tests_ssr::fetch_data().await;
+                count_tx.set(num.parse().unwrap());
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get().map(|_| "done")}</Suspense> }
+}
+
+#[tokio::test]
+async fn render_contramap() {
+    init_test();
+    let app = view! { <App /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("header is: Hello world (1)"));
+}
+
+#[tokio::test]
+async fn render_lens() {
+    init_test();
+    let app = view! { <LensApp /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("header is: default (42)"));
+}
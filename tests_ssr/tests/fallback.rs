@@ -0,0 +1,96 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use serde::{Deserialize, Serialize};
+use tests_ssr::init_test;
+use tokio::time::timeout;
+
+/// Returns a fallback that counts how many times it was computed.
+fn counted(calls: &Arc<AtomicUsize>, value: &'static str) -> impl FnOnce() -> String + Send + Sync {
+    let calls = calls.clone();
+    move || {
+        calls.fetch_add(1, Ordering::Relaxed);
+        value.to_string()
+    }
+}
+
+#[tokio::test]
+async fn fallback_unused() {
+    init_test();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let (msg_res, msg_tx) = async_signal_with_fallback(counted(&calls, "fallback message"));
+    let msg_tx2 = msg_tx.clone();
+    drop(msg_tx);
+    assert_eq!(calls.load(Ordering::Relaxed), 0);
+    drop(msg_tx2);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(msg_res.await, "fallback message");
+}
+
+#[tokio::test]
+async fn fallback_not_computed_when_written() {
+    init_test();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let (msg_res, msg_tx) = async_signal_with_fallback(counted(&calls, "fallback message"));
+    msg_tx.set("written message".to_string());
+    drop(msg_tx);
+    assert_eq!(msg_res.await, "written message");
+    assert_eq!(calls.load(Ordering::Relaxed), 0);
+}
+
+/// A value without a meaningful default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Author {
+    name: String,
+    posts: usize,
+}
+
+#[tokio::test]
+async fn fallback_without_default() {
+    init_test();
+    let (author_res, author_tx) =
+        async_signal_with_fallback(|| Author { name: "Anonymous".to_string(), posts: 0 });
+    // There is no value to update in place yet, so this is ignored, and the
+    // signal still falls back.
+    author_tx
+        .lens(|author: &mut Author| &mut author.posts)
+        .set(3);
+    drop(author_tx);
+    assert_eq!(author_res.await, Author { name: "Anonymous".to_string(), posts: 0 });
+}
+
+#[tokio::test]
+async fn lens_without_default_once_written() {
+    init_test();
+    let (author_res, author_tx) =
+        async_signal_with_fallback(|| Author { name: "Anonymous".to_string(), posts: 0 });
+    author_tx.set(Author { name: "Jane".to_string(), posts: 1 });
+    author_tx
+        .lens(|author: &mut Author| &mut author.posts)
+        .set(3);
+    drop(author_tx);
+    assert_eq!(author_res.await, Author { name: "Jane".to_string(), posts: 3 });
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (msg_res, _) = async_signal_with_async_fallback(|| async {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        "looked up message".to_string()
+    });
+    view! { <Suspense>{move || msg_res.get().map(|msg| format!("msg is: {msg}"))}</Suspense> }
+}
+
+#[tokio::test]
+async fn render_async_fallback() {
+    init_test();
+    let app = view! { <App /> };
+    let html = timeout(Duration::from_secs(1), app.to_html_stream_in_order().collect::<String>())
+        .await
+        .expect("SSR should not have timed out");
+    assert!(html.contains("msg is: looked up message"), "{html}");
+}
@@ -0,0 +1,62 @@
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test_hydration;
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (crumbs_rx, crumbs_tx) = async_signal_split("no crumbs".to_string());
+    let (badge_rx, badge_tx) = async_signal_split("no badge".to_string());
+    let (actions_rx, actions_tx) = async_signal_split(0u32);
+    let header_res = join_async((crumbs_rx, badge_rx, actions_rx)).resource();
+    view! {
+        <Suspense>
+            { move || {
+                let msg = match header_res.get() {
+                    None => "no header yet".to_owned(),
+                    Some((crumbs, badge, actions)) => {
+                        format!("header is: {crumbs}, {badge}, {actions}")
+                    }
+                };
+                view! { <span id="msg">{msg}</span> }
+            }
+        }
+        </Suspense>
+        <Component delay=3 value="Home".to_string() tx=crumbs_tx />
+        <Component delay=1 value="Admin".to_string() tx=badge_tx />
+        <Component delay=2 value=7 tx=actions_tx />
+    }
+}
+
+#[component]
+fn Component<T>(delay: u64, value: T, tx: AsyncWriteSignal<T>) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let tx = tx.clone();
+            let value = value.clone();
+            async move {
+                tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                tx.set(value);
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get().map(|_| "done")}</Suspense> }
+}
+
+#[tokio::test]
+async fn render_join() {
+    let (_owner, shared_context) = init_test_hydration();
+    let app = view! { <App /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("header is: Home, Admin, 7"));
+
+    // The joined value is serialized once, as a whole.
+    let data = shared_context.consume_buffers().await;
+    let data = data.into_iter().map(|(_, data)| data).collect::<Vec<_>>();
+    assert_eq!(data.iter().filter(|data| data.contains("Home")).count(), 1);
+    assert!(data.contains(&r#"["Home","Admin",7]"#.to_string()));
+}
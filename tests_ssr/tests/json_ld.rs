@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::breadcrumbs::{
+    use_breadcrumbs, use_crumb, BreadcrumbTrail, BreadcrumbTrailEnd,
+};
+use leptos_async_signal::json_ld::{BreadcrumbList, JsonLd};
+use leptos_async_signal::*;
+use leptos_router::components::{Outlet, ParentRoute, Route, Router, Routes};
+use leptos_router::location::RequestUrl;
+use leptos_router::path;
+use serde::Serialize;
+use tests_ssr::init_test_hydration;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Article {
+    headline: String,
+}
+
+#[component]
+fn ArticlePage() -> impl IntoView {
+    let (article_rx, article_tx) = async_signal_split(Article { headline: String::new() });
+    let article = ArcResource::new(
+        || (),
+        move |_| {
+            let article_tx = article_tx.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                let headline = "Closing </script><script>alert(1)</script> & more".to_string();
+                article_tx.set(Article { headline: headline.clone() });
+                headline
+            }
+        },
+    );
+    view! {
+        <JsonLd signal=article_rx />
+        <Suspense>{move || article.get()}</Suspense>
+    }
+}
+
+#[tokio::test]
+async fn escaped_script() {
+    let (_owner, _) = init_test_hydration();
+    let app = view! { <ArticlePage /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    let script = concat!(
+        r#"<script type="application/ld+json">"#,
+        r#"{"headline":"Closing \u003c/script\u003e\u003cscript\u003ealert(1)"#,
+        r#"\u003c/script\u003e \u0026 more"}</script>"#,
+    );
+    assert!(html.contains(script), "{html}");
+    assert_eq!(html.matches("<script").count(), 1, "{html}");
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <BreadcrumbTrail />
+            <Routes fallback=|| "Not found">
+                <ParentRoute path=path!("") view=Root>
+                    <Route path=path!("docs") view=Docs />
+                </ParentRoute>
+            </Routes>
+            <BreadcrumbTrailEnd />
+        </Router>
+    }
+}
+
+#[component]
+fn Root() -> impl IntoView {
+    use_crumb(async { "Home".to_string() });
+    let crumbs = use_breadcrumbs().expect("within the trail");
+    let structured = crumbs.map(|crumbs| {
+        crumbs
+            .iter()
+            .collect::<BreadcrumbList>()
+            .base_url("https://example.com/")
+    });
+    view! {
+        <JsonLd signal=structured />
+        <Outlet />
+    }
+}
+
+#[component]
+fn Docs() -> impl IntoView {
+    use_crumb(async {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        "Docs".to_string()
+    });
+    "Docs page"
+}
+
+#[tokio::test]
+async fn breadcrumb_list() {
+    let (_owner, _) = init_test_hydration();
+    provide_context(RequestUrl::new("/docs"));
+    let app = view! { <App /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    let script = concat!(
+        r#"<script type="application/ld+json">"#,
+        r#"{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":["#,
+        r#"{"@type":"ListItem","position":1,"name":"Home","item":"https://example.com/"},"#,
+        r#"{"@type":"ListItem","position":2,"name":"Docs","item":"https://example.com/docs"}"#,
+        r#"]}</script>"#,
+    );
+    assert!(html.contains(script), "{html}");
+}
+
+#[test]
+fn breadcrumb_list_without_url() {
+    let list = BreadcrumbList::default()
+        .item("Home", Some("/"))
+        .item("Post", None::<String>);
+    let json = leptos_async_signal::json_ld::to_json_ld(&list).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":["#,
+            r#"{"@type":"ListItem","position":1,"name":"Home","item":"/"},"#,
+            r#"{"@type":"ListItem","position":2,"name":"Post"}]}"#,
+        )
+    );
+}
@@ -0,0 +1,60 @@
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use serde::{Deserialize, Serialize};
+use tests_ssr::init_test_hydration;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+struct Post {
+    title: String,
+    body: String,
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (post_rx, post_tx) = async_signal_split(Post::default());
+    let title_res = post_rx
+        .map(|post| format!("title is: {}", post.title))
+        .resource();
+    view! {
+        <Suspense>
+            { move || {
+                let msg = title_res.get().unwrap_or_else(|| "no title yet".to_owned());
+                view! { <span id="msg">{msg}</span> }
+            }
+        }
+        </Suspense>
+        <Component post_tx />
+    }
+}
+
+#[component]
+fn Component(post_tx: AsyncWriteSignal<Post>) -> impl IntoView {
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let post_tx = post_tx.clone();
+            async move {
+                let (title, body) = tests_ssr::fetch_data().await;
+                post_tx.set(Post { title, body: format!("body of {body}") });
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get().map(|_| "done")}</Suspense> }
+}
+
+#[tokio::test]
+async fn render_map() {
+    let (_owner, shared_context) = init_test_hydration();
+    let app = view! { <App /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("title is: Hello world"));
+
+    // Only the mapped value is serialized, not the full post.
+    let data = shared_context.consume_buffers().await;
+    let data = data.into_iter().map(|(_, data)| data).collect::<Vec<_>>();
+    assert!(data
+        .iter()
+        .any(|data| data.contains("title is: Hello world")));
+    assert!(!data.iter().any(|data| data.contains("body of 42")));
+}
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use hydration_context::SharedContext;
+use leptos::prelude::*;
+use leptos_async_signal::meta::{AsyncLink, AsyncMeta, AsyncTitle};
+use leptos_async_signal::*;
+use leptos_meta::{provide_meta_context, MetaTags, ServerMetaContext};
+use tests_ssr::init_test_hydration;
+
+#[component]
+fn App() -> impl IntoView {
+    provide_meta_context();
+    let (title_rx, title_tx) = async_signal_split(String::from("Untitled"));
+    let (description_rx, description_tx) = async_signal_split(String::new());
+    let (canonical_rx, canonical_tx) = async_signal_split(String::new());
+    let page = ArcResource::new(
+        || (),
+        move |_| {
+            let (title_tx, description_tx, canonical_tx) =
+                (title_tx.clone(), description_tx.clone(), canonical_tx.clone());
+            async move {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                title_tx.set("Post".to_string());
+                description_tx.set("A post".to_string());
+                canonical_tx.set("/post".to_string());
+                "Post body".to_string()
+            }
+        },
+    );
+    view! {
+        <AsyncTitle signal=title_rx formatter=|title| format!("{title} | Blog") />
+        <AsyncMeta signal=description_rx name="description" />
+        <AsyncLink signal=canonical_rx rel="canonical" />
+        <main>
+            <Suspense fallback=|| "Loading">{move || page.get()}</Suspense>
+        </main>
+    }
+}
+
+#[tokio::test]
+async fn meta_tags_in_head() {
+    let (_owner, shared_context) = init_test_hydration();
+    let (meta_context, meta_output) = ServerMetaContext::new();
+    provide_context(meta_context);
+    let app = view! {
+        <html>
+            <head>
+                <MetaTags />
+            </head>
+            <body>
+                <App />
+            </body>
+        </html>
+    };
+    // The same as the integrations do for out-of-order streaming.
+    let stream = app.to_html_stream_out_of_order();
+    while let Some(pending) = shared_context.await_deferred() {
+        pending.await;
+    }
+    let html = meta_output
+        .inject_meta_context(stream)
+        .await
+        .collect::<String>()
+        .await;
+    let head = &html[..html.find("</head>").expect("head")];
+    assert!(head.contains("<title>Post | Blog</title>"), "{html}");
+    assert!(head.contains(r#"<meta name="description" content="A post">"#), "{html}");
+    assert!(head.contains(r#"<link href="/post" rel="canonical">"#), "{html}");
+    assert!(html.contains("Post body"), "{html}");
+}
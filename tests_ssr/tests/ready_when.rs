@@ -0,0 +1,91 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use serde::{Deserialize, Serialize};
+use tests_ssr::init_test;
+use tokio::time::timeout;
+
+/// The header of a search page, whose result count is not known while loading.
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct SearchHeader {
+    count: Option<usize>,
+}
+
+#[component]
+pub fn App(write_count: bool) -> impl IntoView {
+    let (header_res, header_tx) = AsyncSignalBuilder::new(SearchHeader::default())
+        .ready_when(|header| header.count.is_some())
+        .build();
+    // The writer is taken by the fetcher, so that it is released afterwards.
+    let header_tx = Mutex::new(Some(header_tx));
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let header_tx = header_tx.lock().unwrap().take().unwrap();
+            async move {
+                header_tx.set(SearchHeader { count: None });
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                if write_count {
+                    header_tx.set(SearchHeader { count: Some(3) });
+                }
+            }
+        },
+    );
+    view! {
+        <Suspense>
+            {move || {
+                header_res
+                    .get()
+                    .map(|header| match header.count {
+                        Some(count) => format!("{count} results"),
+                        None => "loading".to_string(),
+                    })
+            }}
+        </Suspense>
+        <Suspense>{move || data.get().map(|_| "done")}</Suspense>
+    }
+}
+
+async fn render(write_count: bool) -> String {
+    init_test();
+    let app = view! { <App write_count /> };
+    timeout(Duration::from_secs(1), app.to_html_stream_in_order().collect::<String>())
+        .await
+        .expect("SSR should not have timed out")
+}
+
+#[tokio::test]
+async fn ready_when_satisfied() {
+    let html = render(true).await;
+    assert!(html.contains("3 results"), "{html}");
+    assert!(!html.contains("loading"), "{html}");
+}
+
+#[tokio::test]
+async fn ready_when_released() {
+    let html = render(false).await;
+    assert!(html.contains("loading"), "{html}");
+}
+
+#[tokio::test]
+async fn ready_when_stream() {
+    init_test();
+    let (header_rx, header_tx) = AsyncSignalBuilder::new(0)
+        .ready_when(|count| *count > 1)
+        .build_split();
+    let stream = header_rx.to_stream();
+    tokio::spawn(async move {
+        for count in [1, 2, 1] {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            header_tx.set(count);
+        }
+    });
+    let counts = timeout(Duration::from_secs(1), stream.collect::<Vec<_>>())
+        .await
+        .expect("stream should end once the writer is released");
+    // Once ready, the signal stays ready.
+    assert_eq!(counts, [2, 1]);
+}
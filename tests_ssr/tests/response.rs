@@ -0,0 +1,67 @@
+use futures::StreamExt;
+use http::header::CACHE_CONTROL;
+use http::{HeaderValue, StatusCode};
+use hydration_context::SharedContext;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use leptos_axum::ResponseOptions;
+use tests_ssr::init_test_hydration;
+
+#[component]
+pub fn App(mode: ResponseMode, found: bool) -> impl IntoView {
+    let (status_rx, status_tx) = async_signal_split(StatusCode::OK);
+    bind_response(&status_rx, mode, |status| {
+        AsyncResponse::default()
+            .status(*status)
+            .header(CACHE_CONTROL, HeaderValue::from_static("no-store"))
+    });
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let status_tx = status_tx.clone();
+            async move {
+                let (title, _) = tests_ssr::fetch_data().await;
+                status_tx.set(if found { StatusCode::OK } else { StatusCode::NOT_FOUND });
+                title
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get()}</Suspense> }
+}
+
+#[tokio::test]
+async fn blocking_delays_head() {
+    let (_owner, shared_context) = init_test_hydration();
+    let options = ResponseOptions::default();
+    provide_context(options.clone());
+    let app = view! { <App mode=ResponseMode::Blocking found=false /> };
+    let _stream = app.to_html_stream_out_of_order();
+
+    // The response head is delayed until the status is known.
+    assert_eq!(options.0.read().status, None);
+    let mut deferred = false;
+    while let Some(pending) = shared_context.await_deferred() {
+        pending.await;
+        deferred = true;
+    }
+    assert!(deferred);
+    let parts = options.0.read();
+    assert_eq!(parts.status, Some(StatusCode::NOT_FOUND));
+    assert_eq!(parts.headers.get(CACHE_CONTROL).unwrap(), "no-store");
+}
+
+#[tokio::test]
+async fn non_blocking_async() {
+    let (_owner, shared_context) = init_test_hydration();
+    let options = ResponseOptions::default();
+    provide_context(options.clone());
+    let app = view! { <App mode=ResponseMode::NonBlocking found=true /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("Hello world"));
+
+    // Nothing delays the response head, but the status is set once all data
+    // is resolved.
+    assert!(shared_context.await_deferred().is_none());
+    shared_context.consume_buffers().await;
+    assert_eq!(options.0.read().status, Some(StatusCode::OK));
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use serde::{Deserialize, Serialize};
+use tests_ssr::init_test;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct NotFound(u32);
+
+impl fmt::Display for NotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "post not found: {}", self.0)
+    }
+}
+
+impl std::error::Error for NotFound {}
+
+#[component]
+pub fn App(id: u32) -> impl IntoView {
+    let (title_res, title_tx) = async_result_signal::<String, NotFound>("default".to_string());
+    view! {
+        <Suspense>
+            <ErrorBoundary fallback=|errors| {
+                let msg = errors
+                    .get()
+                    .into_iter()
+                    .map(|(_, err)| format!("error is: {err}"))
+                    .collect::<String>();
+                view! { <span id="err">{msg}</span> }
+            }>
+                { move || title_res.get().map(|title| {
+                    title.map(|title| view! { <span id="msg">{format!("title is: {title}")}</span> })
+                })}
+            </ErrorBoundary>
+        </Suspense>
+        <Component id title_tx />
+    }
+}
+
+#[component]
+fn Component(id: u32, title_tx: AsyncResultWriteSignal<String, NotFound>) -> impl IntoView {
+    let data = ArcResource::new(
+        move || id,
+        move |id| {
+            let title_tx = title_tx.clone();
+            async move {
+                let (msg, _) = tests_ssr::fetch_data().await;
+                match id {
+                    42 => title_tx.set(msg),
+                    _ => title_tx.fail(NotFound(id)),
+                }
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get().map(|_| "done")}</Suspense> }
+}
+
+#[tokio::test]
+async fn render_ok() {
+    init_test();
+    let app = view! { <App id=42 /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("title is: Hello world"));
+}
+
+#[tokio::test]
+async fn render_err() {
+    init_test();
+    let app = view! { <App id=7 /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("error is: post not found: 7"));
+    assert!(!html.contains("title is"));
+}
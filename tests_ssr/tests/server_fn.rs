@@ -0,0 +1,77 @@
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test;
+
+#[server]
+async fn title_by_id(id: u32) -> Result<String, ServerFnError> {
+    tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+    match id {
+        42 => Ok("The answer".to_string()),
+        _ => Err(ServerFnError::ServerError(format!("No title for: {id}"))),
+    }
+}
+
+#[component]
+pub fn App(id: u32) -> impl IntoView {
+    let (msg_res, msg_tx) = async_signal("default message".to_string());
+    view! {
+        <Suspense>
+            { move || {
+                let msg = match msg_res.get() {
+                    None => "no msg yet".to_owned(),
+                    Some(msg) => format!("msg is: {msg}")
+                };
+                view! { <span id="msg">{msg}</span> }
+            }
+        }
+        </Suspense>
+        <Component id msg_tx />
+    }
+}
+
+#[component]
+fn Component(id: u32, msg_tx: AsyncWriteSignal<String>) -> impl IntoView {
+    let data = ArcResource::new(
+        move || id,
+        move |id| {
+            title_by_id(id).set_signal(
+                msg_tx.clone(),
+                |title| title.clone(),
+                |_| "fallback".to_string(),
+            )
+        },
+    );
+    view! {
+        <Suspense>
+            { move || {
+                    match data.get() {
+                        Some(Ok(title)) => {
+                            view! { <span>{format!("The title is: {title}")}</span> }.into_any()
+                        }
+                        Some(Err(_)) => view! { <span>No title</span> }.into_any(),
+                        None => view! { <span>Loading</span> }.into_any(),
+                    }
+                }
+            }
+        </Suspense>
+    }
+}
+
+#[tokio::test]
+async fn render_ok() {
+    init_test();
+    let app = view! { <App id=42 /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("msg is: The answer"));
+    assert!(html.contains("The title is: The answer"));
+}
+
+#[tokio::test]
+async fn render_err_fallback() {
+    init_test();
+    let app = view! { <App id=7 /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("msg is: fallback"));
+    assert!(html.contains("No title"));
+}
@@ -0,0 +1,63 @@
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test_hydration;
+
+/// An internal value, which is not serializable.
+#[derive(Clone)]
+struct InternalId(String);
+
+#[component]
+fn App(id_tx: AsyncWriteSignal<InternalId>) -> impl IntoView {
+    let (msg_res, msg_tx) = async_signal("default message".to_string());
+    view! {
+        <Suspense>{move || msg_res.get().map(|msg| format!("msg is: {msg}"))}</Suspense>
+        <Component id_tx msg_tx />
+    }
+}
+
+#[component]
+fn Component(
+    id_tx: AsyncWriteSignal<InternalId>,
+    msg_tx: AsyncWriteSignal<String>,
+) -> impl IntoView {
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let id_tx = id_tx.clone();
+            let msg_tx = msg_tx.clone();
+            async move {
+                let (msg, num) = tests_ssr::fetch_data().await;
+                id_tx.set(InternalId(format!("internal-{num}")));
+                msg_tx.set(msg);
+                num
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get()}</Suspense> }
+}
+
+#[tokio::test]
+async fn render_server_only() {
+    let (_owner, shared_context) = init_test_hydration();
+    let (id_res, id_tx) = async_signal_server_only(InternalId("no id".to_string()));
+    let app = view! { <App id_tx /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("msg is: Hello world"), "{html}");
+    // The internal value is consumed on the server (e.g. for logging), rather
+    // than rendered, as the client could not hydrate it.
+    assert_eq!(id_res.await.0, "internal-42");
+
+    // Only the regular async signal is serialized to the client.
+    let data = shared_context.consume_buffers().await;
+    assert!(data.iter().any(|(_, data)| data.contains("Hello world")));
+    assert!(!data.iter().any(|(_, data)| data.contains("internal-42")));
+}
+
+#[tokio::test]
+async fn server_only_unused() {
+    let (_owner, _) = init_test_hydration();
+    let (id_res, id_tx) = async_signal_server_only(InternalId("no id".to_string()));
+    drop(id_tx);
+    assert_eq!(id_res.await.0, "no id");
+}
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use serde::{Deserialize, Serialize};
+use tests_ssr::{init_test, init_test_hydration};
+
+#[tokio::test]
+async fn equal_write_is_ready() {
+    init_test();
+    let (msg_rx, msg_tx) = async_signal_split("default".to_string());
+    let mut stream = Box::pin(msg_rx.to_stream());
+    // An equal write still makes the value ready.
+    assert!(!msg_tx.set_if_changed("default".to_string()));
+    assert_eq!(stream.next().await.as_deref(), Some("default"));
+
+    // But only changed writes are yielded afterwards.
+    assert!(!msg_tx.set_if_changed("default".to_string()));
+    assert!(msg_tx.set_if_changed("changed".to_string()));
+    drop(msg_tx);
+    let msgs = tokio::time::timeout(Duration::from_secs(1), stream.collect::<Vec<_>>())
+        .await
+        .expect("stream should end once the writer is released");
+    assert_eq!(msgs, ["changed"]);
+}
+
+#[tokio::test]
+async fn superseded_write_is_discarded() {
+    init_test();
+    let (msg_rx, msg_tx) = async_signal_split("default".to_string());
+    let stale_tx = msg_tx.supersede();
+    let current_tx = msg_tx.supersede();
+    assert!(!stale_tx.set_if_changed("stale".to_string()));
+    // The current signal compares against the value it writes to.
+    assert!(!current_tx.set_if_changed("default".to_string()));
+    assert!(current_tx.set_if_changed("current".to_string()));
+    assert_eq!(msg_rx.resource().await, "current");
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Page {
+    title: String,
+    views: u32,
+}
+
+#[tokio::test]
+async fn lens_compares_its_part() {
+    init_test();
+    let (page_rx, page_tx) = async_signal_split(Page { title: "Home".to_string(), views: 0 });
+    let mut pages = Box::pin(page_rx.to_stream());
+    let title_tx = page_tx.lens(|page: &mut Page| &mut page.title);
+    assert!(!title_tx.set_if_changed("Home".to_string()));
+    assert_eq!(pages.next().await.map(|page| page.title).as_deref(), Some("Home"));
+
+    assert!(title_tx.set_if_changed("About".to_string()));
+    assert!(!title_tx.set_if_changed("About".to_string()));
+    drop((page_tx, title_tx));
+    let pages = tokio::time::timeout(Duration::from_secs(1), pages.collect::<Vec<_>>())
+        .await
+        .expect("stream should end once the writers are released");
+    assert_eq!(pages, [Page { title: "About".to_string(), views: 0 }]);
+}
+
+#[tokio::test]
+async fn lens_before_fallback_is_discarded() {
+    init_test();
+    let (page_res, page_tx) =
+        async_signal_with_fallback(|| Page { title: "Not found".to_string(), views: 0 });
+    let title_tx = page_tx.lens(|page: &mut Page| &mut page.title);
+    assert!(!title_tx.set_if_changed("About".to_string()));
+    drop((page_tx, title_tx));
+    assert_eq!(page_res.await.title, "Not found");
+}
+
+/// A value that cannot be compared.
+#[derive(Clone, Serialize, Deserialize)]
+struct Shape {
+    name: String,
+    #[serde(skip)]
+    area: Option<Arc<dyn Fn() -> f64 + Send + Sync>>,
+}
+
+#[component]
+fn App() -> impl IntoView {
+    let (shape_res, shape_tx) = async_signal(Shape { name: "none".to_string(), area: None });
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let shape_tx = shape_tx.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                shape_tx.set(Shape { name: "square".to_string(), area: Some(Arc::new(|| 4.0)) });
+            }
+        },
+    );
+    view! {
+        <Suspense>
+            {move || {
+                shape_res
+                    .with(|shape| {
+                        shape.as_ref().map(|shape| {
+                            let area = shape.area.as_ref().map_or(0.0, |area| area());
+                            format!("shape: {} of area {area}", shape.name)
+                        })
+                    })
+            }}
+        </Suspense>
+        <Suspense>{move || data.get().map(|_| "done")}</Suspense>
+    }
+}
+
+#[tokio::test]
+async fn render_without_partial_eq() {
+    let (_owner, _) = init_test_hydration();
+    let app = view! { <App /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("shape: square of area 4"), "{html}");
+}
@@ -0,0 +1,43 @@
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test_hydration;
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (msg_rx, msg_tx) = async_signal_split("default message".to_string());
+    let msg = msg_rx.signal();
+    view! {
+        <Suspense>{move || msg.get().map(|msg| format!("msg is: {msg}"))}</Suspense>
+        <Component msg_tx />
+    }
+}
+
+#[component]
+fn Component(msg_tx: AsyncWriteSignal<String>) -> impl IntoView {
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let msg_tx = msg_tx.clone();
+            async move {
+                let (msg, num) = tests_ssr::fetch_data().await;
+                msg_tx.set(msg);
+                num
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get()}</Suspense> }
+}
+
+#[tokio::test]
+async fn render_signal() {
+    let (_owner, shared_context) = init_test_hydration();
+    let app = view! { <App /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("msg is: Hello world"), "{html}");
+    assert!(!html.contains("default message"), "{html}");
+
+    // The value is carried to the client by the paired resource.
+    let data = shared_context.consume_buffers().await;
+    assert!(data.iter().any(|(_, data)| data.contains("Hello world")));
+}
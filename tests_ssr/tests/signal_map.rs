@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::{init_test, init_test_hydration};
+use tokio::time::timeout;
+
+/// A dashboard, whose panels each publish a summary into the shared header.
+#[component]
+pub fn App(panels: Vec<(&'static str, u64)>) -> impl IntoView {
+    let summaries = async_signal_map::<String, String>("no summary".to_string());
+    let header = summaries.all().resource();
+    // Reading a key whose writer is never obtained would not be ready.
+    let cpu = panels
+        .iter()
+        .any(|(name, _)| *name == "cpu")
+        .then(|| summaries.reader(&"cpu".to_string()).resource());
+    provide_context(summaries);
+    view! {
+        <Suspense>
+            {move || {
+                header
+                    .get()
+                    .map(|header| {
+                        header
+                            .into_iter()
+                            .map(|(panel, summary)| format!("[{panel}: {summary}]"))
+                            .collect::<String>()
+                    })
+            }}
+        </Suspense>
+        <Suspense>
+            {move || {
+                cpu.as_ref()
+                    .and_then(|cpu| cpu.get())
+                    .map(|summary| format!("cpu panel: {summary}"))
+            }}
+        </Suspense>
+        {panels
+            .into_iter()
+            .map(|(name, delay)| view! { <Panel name delay /> })
+            .collect_view()}
+    }
+}
+
+#[component]
+fn Panel(name: &'static str, delay: u64) -> impl IntoView {
+    let summary_tx = expect_context::<AsyncSignalMap<String, String>>().writer(&name.to_string());
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let summary_tx = summary_tx.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                summary_tx.set(format!("{name} ok"));
+                delay
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get()}</Suspense> }
+}
+
+async fn render(panels: Vec<(&'static str, u64)>) -> String {
+    let (_owner, _) = init_test_hydration();
+    let app = view! { <App panels /> };
+    timeout(Duration::from_secs(1), app.to_html_stream_in_order().collect::<String>())
+        .await
+        .expect("SSR should not have timed out")
+}
+
+#[tokio::test]
+async fn render_signal_map() {
+    let html = render(vec![("memory", 10), ("cpu", 1), ("disk", 5)]).await;
+    assert!(html.contains("[cpu: cpu ok][disk: disk ok][memory: memory ok]"), "{html}");
+    assert!(html.contains("cpu panel: cpu ok"), "{html}");
+}
+
+#[tokio::test]
+async fn render_empty_map() {
+    let html = render(vec![]).await;
+    assert!(!html.contains("[") && !html.contains("cpu panel"), "{html}");
+}
+
+#[tokio::test]
+async fn expected_keys() {
+    init_test();
+    let summaries = async_signal_map::<String, u32>(0);
+    summaries.expect(["cpu".to_string(), "disk".to_string()]);
+    let all = summaries.all().resource();
+    let summaries_tx = summaries.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        summaries_tx.writer(&"cpu".to_string()).set(1);
+        // Releasing the writer makes the key ready with its default value.
+        drop(summaries_tx.writer(&"disk".to_string()));
+    });
+    let all = timeout(Duration::from_secs(1), all).await.unwrap();
+    let all = all
+        .iter()
+        .map(|(key, value)| (key.as_str(), *value))
+        .collect::<Vec<_>>();
+    assert_eq!(all, [("cpu", 1), ("disk", 0)]);
+}
@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test_hydration;
+use tokio::time::timeout;
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (crumbs_res, crumbs_tx) = async_signal("no crumbs".to_string());
+    provide_async_slot(crumbs_tx);
+    view! {
+        <Suspense>{move || crumbs_res.get().map(|crumbs| format!("global crumbs: {crumbs}"))}</Suspense>
+        <SubApp />
+    }
+}
+
+/// A sub-app with its own crumbs slot, which contributes to the global one.
+#[component]
+fn SubApp() -> impl IntoView {
+    let (local_rx, local_tx) = async_signal_split("no local crumbs".to_string());
+    let slot = provide_async_slot(local_tx);
+    let parent = slot.parent().expect("the global slot should be shadowed");
+    local_rx.forward(parent.writer(), |local| format!("Home / {local}"));
+    let local_res = local_rx.resource();
+    view! {
+        <Suspense>{move || local_res.get().map(|crumbs| format!("local crumbs: {crumbs}"))}</Suspense>
+        <Page />
+    }
+}
+
+#[component]
+fn Page() -> impl IntoView {
+    let crumbs_tx = use_async_slot::<String>()
+        .expect("a slot should be provided")
+        .writer();
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let crumbs_tx = crumbs_tx.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                crumbs_tx.set("Settings / Profile".to_string());
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get().map(|_| "done")}</Suspense> }
+}
+
+#[tokio::test]
+async fn render_shadowed_slot() {
+    let (_owner, _) = init_test_hydration();
+    let app = view! { <App /> };
+    let html = timeout(Duration::from_secs(1), app.to_html_stream_in_order().collect::<String>())
+        .await
+        .expect("SSR should not have timed out");
+    assert!(html.contains("local crumbs: Settings / Profile"), "{html}");
+    assert!(html.contains("global crumbs: Home / Settings / Profile"), "{html}");
+}
+
+#[tokio::test]
+async fn slot_scopes() {
+    let (owner, _) = init_test_hydration();
+    assert!(use_async_slot::<String>().is_none());
+    let (_, outer_tx) = async_signal_split("outer".to_string());
+    provide_async_slot(outer_tx);
+    owner.child().with(|| {
+        let (_, inner_tx) = async_signal_split("inner".to_string());
+        let inner = provide_async_slot(inner_tx);
+        assert!(use_async_slot::<String>().is_some());
+        assert!(inner.parent().is_some_and(|outer| outer.parent().is_none()));
+    });
+    // The inner slot is only provided to the child.
+    assert!(use_async_slot::<String>().is_some_and(|outer| outer.parent().is_none()));
+}
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test;
+use tokio::time::{sleep, timeout};
+
+#[tokio::test]
+async fn stream_writes() {
+    init_test();
+    let (msg_rx, msg_tx) = async_signal_split("default".to_string());
+    let stream = msg_rx.to_stream();
+    tokio::spawn(async move {
+        for msg in ["first", "second", "third"] {
+            sleep(Duration::from_millis(5)).await;
+            msg_tx.set(msg.to_string());
+        }
+    });
+    let msgs = timeout(Duration::from_secs(1), stream.collect::<Vec<_>>())
+        .await
+        .expect("stream should end once the writer is released");
+    assert_eq!(msgs, ["first", "second", "third"]);
+}
+
+#[tokio::test]
+async fn stream_unused() {
+    init_test();
+    let (msg_rx, msg_tx) = async_signal_split("default".to_string());
+    let msg_len_rx = msg_rx.map(String::len);
+    drop(msg_tx);
+    let msgs = msg_rx.to_stream().collect::<Vec<_>>().await;
+    assert_eq!(msgs, ["default"]);
+    let msg_lens = msg_len_rx.to_stream().collect::<Vec<_>>().await;
+    assert_eq!(msg_lens, [7]);
+}
@@ -0,0 +1,53 @@
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test_hydration;
+
+#[component]
+pub fn App() -> impl IntoView {
+    let (header_res, crumbs_tx) = async_signal("default crumbs".to_string());
+    let footer_res = crumbs_tx.subscribe();
+    view! {
+        <Suspense>
+            { move || header_res.get().map(|crumbs| format!("header crumbs: {crumbs}")) }
+        </Suspense>
+        <Component crumbs_tx />
+        <Suspense>
+            { move || footer_res.get().map(|crumbs| format!("footer crumbs: {crumbs}")) }
+        </Suspense>
+    }
+}
+
+#[component]
+fn Component(crumbs_tx: AsyncWriteSignal<String>) -> impl IntoView {
+    let data = ArcResource::new(
+        || (),
+        move |_| {
+            let crumbs_tx = crumbs_tx.clone();
+            async move {
+                let (msg, _) = tests_ssr::fetch_data().await;
+                crumbs_tx.set(msg);
+            }
+        },
+    );
+    view! { <Suspense>{move || data.get().map(|_| "done")}</Suspense> }
+}
+
+#[tokio::test]
+async fn render_subscribe() {
+    let (_owner, shared_context) = init_test_hydration();
+    let app = view! { <App /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("header crumbs: Hello world"));
+    assert!(html.contains("footer crumbs: Hello world"));
+
+    // Each reader is hydrated on its own.
+    let data = shared_context.consume_buffers().await;
+    let data = data.into_iter().map(|(_, data)| data).collect::<Vec<_>>();
+    assert_eq!(
+        data.iter()
+            .filter(|data| data.contains("Hello world"))
+            .count(),
+        2
+    );
+}
@@ -0,0 +1,53 @@
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use tests_ssr::init_test;
+
+#[tokio::test]
+async fn superseded_writes_are_ignored() {
+    init_test();
+    let (rx, tx) = async_signal_split(0);
+    let first = tx.supersede();
+    let second = tx.supersede();
+    second.set(2);
+    first.set(1);
+    drop((first, second, tx));
+    assert_eq!(rx.to_stream().collect::<Vec<_>>().await, vec![2]);
+}
+
+#[tokio::test]
+async fn writes_after_owner_cleanup_are_ignored() {
+    init_test();
+    let (rx, tx) = async_signal_split(0);
+    let owner = Owner::new();
+    let writer = owner.with(|| tx.supersede());
+    owner.cleanup();
+    writer.set(1);
+    // Plain clones are not affected.
+    tx.set(10);
+    drop((writer, tx));
+    assert_eq!(rx.to_stream().collect::<Vec<_>>().await, vec![10]);
+}
+
+#[tokio::test]
+async fn ignored_writes_do_not_make_ready() {
+    init_test();
+    let (rx, tx) = async_signal_split(0);
+    let first = tx.supersede();
+    let _second = tx.supersede();
+    first.set(1);
+    let mut stream = Box::pin(rx.to_stream());
+    let next = tokio::time::timeout(std::time::Duration::from_millis(10), stream.next());
+    assert!(next.await.is_err());
+}
+
+#[tokio::test]
+async fn superseded_reader() {
+    init_test();
+    let (_rx, tx) = async_signal_split(0);
+    let writer = tx.supersede();
+    let reader = writer.reader();
+    writer.set(1);
+    drop((writer, tx));
+    assert_eq!(reader.to_stream().collect::<Vec<_>>().await, vec![1]);
+}
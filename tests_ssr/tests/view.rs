@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos_async_signal::*;
+use leptos_router::components::{Route, Router, Routes};
+use leptos_router::location::RequestUrl;
+use leptos_router::{path, SsrMode};
+use tests_ssr::init_test_hydration;
+
+#[component]
+fn App(transition: bool) -> impl IntoView {
+    let (title_rx, title_tx) = async_signal_split(String::from("Untitled"));
+    provide_context(title_tx);
+    view! {
+        <Router>
+            <h1>
+                <AsyncView signal=title_rx transition fallback=|| "Loading" let:title>
+                    {title}
+                </AsyncView>
+            </h1>
+            <Routes fallback=|| "Not found">
+                <Route path=path!("page") ssr=SsrMode::Async view=Page />
+            </Routes>
+        </Router>
+    }
+}
+
+#[component]
+fn Page() -> impl IntoView {
+    let title_tx = expect_context::<AsyncWriteSignal<String>>();
+    let body = ArcResource::new(
+        || (),
+        move |_| {
+            let title_tx = title_tx.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                title_tx.set("Page title".to_string());
+                "Page body".to_string()
+            }
+        },
+    );
+    view! { <Suspense>{move || body.get()}</Suspense> }
+}
+
+async fn render(transition: bool) -> String {
+    let (_owner, _) = init_test_hydration();
+    provide_context(RequestUrl::new("/page"));
+    let app = view! { <App transition /> };
+    app.to_html_stream_in_order().collect::<String>().await
+}
+
+#[tokio::test]
+async fn suspense_view() {
+    let html = render(false).await;
+    assert!(html.contains("<h1>Page title"), "{html}");
+    assert!(html.contains("Page body"), "{html}");
+    assert!(!html.contains("Loading"), "{html}");
+}
+
+#[tokio::test]
+async fn transition_view() {
+    let html = render(true).await;
+    assert!(html.contains("<h1>Page title"), "{html}");
+    assert!(html.contains("Page body"), "{html}");
+    assert!(!html.contains("Loading"), "{html}");
+}